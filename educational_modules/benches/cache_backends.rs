@@ -0,0 +1,64 @@
+// ========================================================================
+// RANDY'S CACHE BACKEND BENCHMARK
+// Mutex vs RwLock vs DashMap under parallel_fibonacci_range
+// ========================================================================
+//
+// Runs the same parallel range against a fresh calculator per round for
+// every backend, reports throughput, and asserts all backends agree.
+// Run with `cargo bench --bench cache_backends --features dashmap`.
+
+use std::collections::HashMap;
+use std::time::Instant;
+
+#[cfg(feature = "dashmap")]
+use randy_rust_fibonacci::DashMapCache;
+use randy_rust_fibonacci::{CacheBackend, CannabisStrain, MutexCache, RandyCannabisFibonacci, RwLockCache};
+
+/// Indices computed each round (Sativa stays below its overflow at 147)
+const RANGE_START: u64 = 0;
+const RANGE_END: u64 = 147;
+
+/// Fresh calculators per backend; each starts with a cold cache
+const ROUNDS: u32 = 200;
+
+/// Time `ROUNDS` cold parallel runs and return the last run's results
+fn bench_backend<B, F>(name: &str, make_backend: F) -> HashMap<u64, u128>
+where
+    B: CacheBackend + 'static,
+    F: Fn() -> B,
+{
+    let mut results = HashMap::new();
+    let start_time = Instant::now();
+    
+    for _ in 0..ROUNDS {
+        let calculator = RandyCannabisFibonacci::with_backend(CannabisStrain::Sativa, make_backend());
+        results = calculator
+            .parallel_fibonacci_range(RANGE_START, RANGE_END)
+            .expect("benchmark range stays below the Sativa overflow boundary");
+    }
+    
+    let elapsed = start_time.elapsed();
+    let values = ROUNDS as f64 * (RANGE_END - RANGE_START) as f64;
+    println!("{:<8} {:>12.2?} total {:>12.0} values/sec", name, elapsed, values / elapsed.as_secs_f64());
+    
+    results
+}
+
+fn main() {
+    println!("Cache backends over F({})..F({}), {} rounds each", RANGE_START, RANGE_END - 1, ROUNDS);
+    
+    let baseline = bench_backend("Mutex", MutexCache::default);
+    
+    // Correctness guard: a faster backend is useless if it changes the answers
+    let rwlock = bench_backend("RwLock", RwLockCache::default);
+    assert_eq!(rwlock, baseline, "RwLockCache results differ from MutexCache");
+    
+    #[cfg(feature = "dashmap")]
+    {
+        let dashmap = bench_backend("DashMap", DashMapCache::default);
+        assert_eq!(dashmap, baseline, "DashMapCache results differ from MutexCache");
+    }
+    
+    #[cfg(not(feature = "dashmap"))]
+    println!("DashMap  skipped (enable the `dashmap` feature)");
+}
@@ -0,0 +1,10 @@
+// ========================================================================
+// RANDY'S CANNABIS-ENHANCED RUST FIBONACCI - INTERACTIVE BINARY
+// ========================================================================
+//
+// Thin entry point for the `cli` feature; everything lives in the
+// `randy_rust_fibonacci` library (../randy_cannabis_fibonacci.rs).
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    randy_rust_fibonacci::run_cli()
+}
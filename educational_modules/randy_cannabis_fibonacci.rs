@@ -22,523 +22,5417 @@
 // PROOF OF CORRECTNESS VERIFIED BY THE MOST SOPHISTICATED TYPE
 // CHECKER IN PRODUCTION USE."
 
-use std::collections::HashMap;
-use std::sync::{Arc, Mutex};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::iter::FusedIterator;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::mpsc::Sender;
+use std::ops::Index;
+use std::sync::{Arc, Mutex, OnceLock, PoisonError, RwLock};
 use std::thread;
 use std::time::{Duration, Instant};
+#[cfg(feature = "cli")]
 use std::io::{self, Write};
 
+#[cfg(feature = "bigint")]
+use num_bigint::BigUint;
+#[cfg(feature = "dashmap")]
+use dashmap::DashMap;
+#[cfg(feature = "async")]
+use futures::future::join_all;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Spawn a worker thread, reporting OS spawn failure as an error
+/// 
+/// `thread::spawn` panics when the OS refuses a new thread; the
+/// `Builder` form returns that failure so the public API never panics.
+fn spawn_worker<F, T>(work: F) -> Result<thread::JoinHandle<T>, String>
+where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    thread::Builder::new()
+        .spawn(work)
+        .map_err(|error| format!("Failed to spawn worker thread: {}", error))
+}
+
+/// Classic Fibonacci values F(0)..=F(20), built at compile time
+/// 
+/// Demonstrates `const fn` evaluation: the hottest small indices become a
+/// static lookup with no runtime computation. The first hit up to each
+/// index copies the table prefix into the cache, so the cache looks just
+/// as if the recursion had run; every later hit skips the lock entirely.
+pub const FIB_TABLE: [u128; 21] = build_fib_table();
+
+const fn build_fib_table() -> [u128; 21] {
+    let mut table = [0u128; 21];
+    table[1] = 1;
+    
+    let mut i = 2;
+    while i < table.len() {
+        table[i] = table[i - 1] + table[i - 2];
+        i += 1;
+    }
+    
+    table
+}
+
+/// Multiply by `numerator / denominator` without intermediate overflow
+/// 
+/// Splits `value` into quotient and remainder by the denominator so
+/// value·num/den is exact and only errors when the result itself
+/// exceeds u128.
+fn scale_by_fraction(value: u128, (numerator, denominator): (u128, u128)) -> Option<u128> {
+    let (quotient, remainder) = (value / denominator, value % denominator);
+    
+    quotient
+        .checked_mul(numerator)
+        .and_then(|scaled| scaled.checked_add(remainder.checked_mul(numerator)? / denominator))
+}
+
+/// Pluggable memoization storage
+/// 
+/// Demonstrates dependency inversion: the calculator only needs get/put,
+/// so a `DashMap`, an LRU or a test double can stand in for the default
+/// `Mutex<HashMap>`. Methods take `&self`; backends handle their own
+/// synchronization since workers share one backend across threads.
+/// 
+/// The built-in backends recover from lock poisoning instead of
+/// panicking: every write is a single insert, so the map stays consistent.
+pub trait CacheBackend: Send + Sync {
+    /// Cached value for index `n`, if present
+    fn get(&self, n: u64) -> Option<u128>;
+    /// Store the value for index `n`
+    fn put(&self, n: u64, value: u128);
+    /// Remove the entry for index `n`, if present
+    fn remove(&self, n: u64);
+    /// Every index currently stored, in any order
+    fn indices(&self) -> Vec<u64>;
+    
+    /// Number of stored entries
+    fn len(&self) -> usize {
+        self.indices().len()
+    }
+    
+    /// Whether the backend holds no entries
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    
+    /// `get`, adding nanoseconds spent acquiring locks to `wait_nanos`
+    /// 
+    /// The default cannot see inside `get`, so it times the whole call;
+    /// backends guarding a map with one lock override it to time only
+    /// the acquisition.
+    fn get_timed(&self, n: u64, wait_nanos: &AtomicU64) -> Option<u128> {
+        timed_acquire(wait_nanos, || self.get(n))
+    }
+    
+    /// `put`, adding nanoseconds spent acquiring locks to `wait_nanos`
+    fn put_timed(&self, n: u64, value: u128, wait_nanos: &AtomicU64) {
+        timed_acquire(wait_nanos, || self.put(n, value))
+    }
+}
+
+/// Run `acquire` (typically a lock call), adding its duration to `wait_nanos`
+fn timed_acquire<T>(wait_nanos: &AtomicU64, acquire: impl FnOnce() -> T) -> T {
+    let start_time = Instant::now();
+    let acquired = acquire();
+    wait_nanos.fetch_add(start_time.elapsed().as_nanos() as u64, Ordering::Relaxed);
+    acquired
+}
+
+/// Default cache backend: a `HashMap` behind a `Mutex`
+#[derive(Debug, Default)]
+pub struct MutexCache {
+    map: Mutex<HashMap<u64, u128>>,
+}
+
+impl CacheBackend for MutexCache {
+    fn get(&self, n: u64) -> Option<u128> {
+        self.map.lock().unwrap_or_else(PoisonError::into_inner).get(&n).copied()
+    }
+    
+    fn put(&self, n: u64, value: u128) {
+        self.map.lock().unwrap_or_else(PoisonError::into_inner).insert(n, value);
+    }
+    
+    fn remove(&self, n: u64) {
+        self.map.lock().unwrap_or_else(PoisonError::into_inner).remove(&n);
+    }
+    
+    fn indices(&self) -> Vec<u64> {
+        self.map.lock().unwrap_or_else(PoisonError::into_inner).keys().copied().collect()
+    }
+    
+    fn len(&self) -> usize {
+        self.map.lock().unwrap_or_else(PoisonError::into_inner).len()
+    }
+    
+    fn get_timed(&self, n: u64, wait_nanos: &AtomicU64) -> Option<u128> {
+        timed_acquire(wait_nanos, || self.map.lock().unwrap_or_else(PoisonError::into_inner)).get(&n).copied()
+    }
+    
+    fn put_timed(&self, n: u64, value: u128, wait_nanos: &AtomicU64) {
+        timed_acquire(wait_nanos, || self.map.lock().unwrap_or_else(PoisonError::into_inner)).insert(n, value);
+    }
+}
+
+/// Cache backend allowing concurrent readers
+/// 
+/// Cache hits only take the read lock, so warm lookups from many
+/// workers proceed in parallel; inserts still serialize on the write lock.
+#[derive(Debug, Default)]
+pub struct RwLockCache {
+    map: RwLock<HashMap<u64, u128>>,
+}
+
+impl CacheBackend for RwLockCache {
+    fn get(&self, n: u64) -> Option<u128> {
+        self.map.read().unwrap_or_else(PoisonError::into_inner).get(&n).copied()
+    }
+    
+    fn put(&self, n: u64, value: u128) {
+        self.map.write().unwrap_or_else(PoisonError::into_inner).insert(n, value);
+    }
+    
+    fn remove(&self, n: u64) {
+        self.map.write().unwrap_or_else(PoisonError::into_inner).remove(&n);
+    }
+    
+    fn indices(&self) -> Vec<u64> {
+        self.map.read().unwrap_or_else(PoisonError::into_inner).keys().copied().collect()
+    }
+    
+    fn len(&self) -> usize {
+        self.map.read().unwrap_or_else(PoisonError::into_inner).len()
+    }
+    
+    fn get_timed(&self, n: u64, wait_nanos: &AtomicU64) -> Option<u128> {
+        timed_acquire(wait_nanos, || self.map.read().unwrap_or_else(PoisonError::into_inner)).get(&n).copied()
+    }
+    
+    fn put_timed(&self, n: u64, value: u128, wait_nanos: &AtomicU64) {
+        timed_acquire(wait_nanos, || self.map.write().unwrap_or_else(PoisonError::into_inner)).insert(n, value);
+    }
+}
+
+/// Sharded lock-per-bucket cache backend from the `dashmap` crate
+#[cfg(feature = "dashmap")]
+#[derive(Debug, Default)]
+pub struct DashMapCache {
+    map: DashMap<u64, u128>,
+}
+
+#[cfg(feature = "dashmap")]
+impl CacheBackend for DashMapCache {
+    fn get(&self, n: u64) -> Option<u128> {
+        self.map.get(&n).map(|entry| *entry)
+    }
+    
+    fn put(&self, n: u64, value: u128) {
+        self.map.insert(n, value);
+    }
+    
+    fn remove(&self, n: u64) {
+        self.map.remove(&n);
+    }
+    
+    fn indices(&self) -> Vec<u64> {
+        self.map.iter().map(|entry| *entry.key()).collect()
+    }
+    
+    fn len(&self) -> usize {
+        self.map.len()
+    }
+}
+
+/// Hit and miss counters for one cache's lookups
+#[derive(Debug, Default)]
+struct CacheStats {
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl CacheStats {
+    /// Zero both counters, returning the `(hits, misses)` they held
+    fn take(&self) -> (u64, u64) {
+        (self.hits.swap(0, Ordering::Relaxed), self.misses.swap(0, Ordering::Relaxed))
+    }
+    
+    /// Add `(hits, misses)` back onto the counters
+    fn add(&self, (hits, misses): (u64, u64)) {
+        self.hits.fetch_add(hits, Ordering::Relaxed);
+        self.misses.fetch_add(misses, Ordering::Relaxed);
+    }
+}
+
 /// Randy's Cannabis-Enhanced Fibonacci Calculator
 /// 
 /// Demonstrates Rust's safety features while computing Fibonacci sequences
 /// with cannabis-strain-specific algorithmic variations and performance analysis.
-#[derive(Debug, Clone)]
-pub struct RandyCannabisFibonacci {
-    cache: Arc<Mutex<HashMap<u64, u128>>>,
-    strain_multiplier: f64,
+/// Generic over its `CacheBackend`, defaulting to `MutexCache`.
+#[derive(Debug)]
+pub struct RandyCannabisFibonacci<B = MutexCache> {
+    cache: Arc<B>,
+    /// Negafibonacci values, kept apart so signs never collide with the u128 cache
+    negative_cache: Arc<Mutex<HashMap<i64, i128>>>,
+    /// Hit/miss counts for `negative_cache`, separate from `stats`
+    negative_stats: Arc<CacheStats>,
+    strain_ratio: (u128, u128),
     strain_name: String,
+    /// True when values are the classic sequence (Hybrid, standard seeds),
+    /// so `FIB_TABLE` can answer small indices directly
+    classic_sequence: bool,
+    /// Highest `FIB_TABLE` index already copied into the cache
+    table_seeded_to: Arc<AtomicU64>,
+    /// When set, accumulates nanoseconds spent acquiring cache locks
+    lock_wait_nanos: Option<Arc<AtomicU64>>,
+    /// Write-once slots for every representable index, so `get` and
+    /// `Index` can hand out `&u128` that outlive the cache lock
+    pinned: Arc<[OnceLock<u128>]>,
+    /// Most entries the cache may hold; past it values are computed, not stored
+    max_cache_entries: Option<usize>,
+    /// Lookup hit/miss counts, shared with clones like the cache itself
+    stats: Arc<CacheStats>,
+}
+
+impl<B> Clone for RandyCannabisFibonacci<B> {
+    fn clone(&self) -> Self {
+        RandyCannabisFibonacci {
+            cache: Arc::clone(&self.cache),
+            negative_cache: Arc::clone(&self.negative_cache),
+            negative_stats: Arc::clone(&self.negative_stats),
+            strain_ratio: self.strain_ratio,
+            strain_name: self.strain_name.clone(),
+            classic_sequence: self.classic_sequence,
+            table_seeded_to: Arc::clone(&self.table_seeded_to),
+            lock_wait_nanos: self.lock_wait_nanos.clone(),
+            pinned: Arc::clone(&self.pinned),
+            max_cache_entries: self.max_cache_entries,
+            stats: Arc::clone(&self.stats),
+        }
+    }
 }
 
 /// Cannabis strain types for algorithmic variation
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum CannabisStrain {
     Sativa,
     Indica, 
     Hybrid,
 }
 
+/// Whether a strain's multiplier speeds the sequence up or slows it down
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SpeedCategory {
+    Faster,
+    Baseline,
+    Slower,
+}
+
 impl CannabisStrain {
+    /// Every strain, in declaration order
+    pub const ALL: [CannabisStrain; 3] = [CannabisStrain::Sativa, CannabisStrain::Indica, CannabisStrain::Hybrid];
+    
     /// Get strain-specific performance characteristics
-    fn characteristics(&self) -> (f64, &'static str, &'static str) {
+    pub fn characteristics(&self) -> (f64, &'static str, &'static str) {
         match self {
             CannabisStrain::Sativa => (1.2, "Energetic", "Fast computation with creative optimizations"),
             CannabisStrain::Indica => (0.8, "Relaxed", "Methodical calculation with deep caching"),
             CannabisStrain::Hybrid => (1.0, "Balanced", "Optimal mix of speed and accuracy"),
         }
     }
-}
-
-impl RandyCannabisFibonacci {
-    /// Create new cannabis-enhanced Fibonacci calculator
-    /// 
-    /// # Arguments
-    /// * `strain` - Cannabis strain for algorithmic personality
+    
+    /// Deterministically pick a strain from a seed
     /// 
-    /// # Examples
-    /// ```
-    /// let calculator = RandyCannabisFibonacci::new(CannabisStrain::Sativa);
-    /// println!("Fibonacci calculator ready with {} enhancement!", calculator.strain_name);
-    /// ```
-    pub fn new(strain: CannabisStrain) -> Self {
-        let (multiplier, name, _) = strain.characteristics();
-        
-        let mut initial_cache = HashMap::new();
-        initial_cache.insert(0, 0);
-        initial_cache.insert(1, 1);
-        
-        RandyCannabisFibonacci {
-            cache: Arc::new(Mutex::new(initial_cache)),
-            strain_multiplier: multiplier,
-            strain_name: format!("{:?}", strain),
+    /// Plain modulo over the strain list - no RNG crate, so demos can vary
+    /// the strain while staying reproducible.
+    pub fn from_seed(seed: u64) -> CannabisStrain {
+        match seed % 3 {
+            0 => CannabisStrain::Sativa,
+            1 => CannabisStrain::Indica,
+            _ => CannabisStrain::Hybrid,
         }
     }
     
-    /// Cannabis-enhanced memoized Fibonacci calculation
+    /// Effective multiplier of a weighted blend of two strains
     /// 
-    /// Demonstrates Rust's memory safety while implementing efficient
-    /// dynamic programming with strain-specific algorithmic variations.
-    pub fn plant_spirit_fibonacci(&self, n: u64) -> Result<u128, String> {
-        if n > 186 {
-            return Err("Fibonacci overflow: Result would exceed u128 capacity".to_string());
-        }
-        
-        // Check cache first (thread-safe access)
-        {
-            let cache = self.cache.lock().unwrap();
-            if let Some(&value) = cache.get(&n) {
-                return Ok(value);
-            }
+    /// Linear interpolation between the strains' multipliers: `weight` 0.0
+    /// is pure `a`, 1.0 is pure `b`. Feed the result to
+    /// `RandyCannabisFibonacci::with_multiplier` for the enhanced variant.
+    pub fn blend(a: CannabisStrain, b: CannabisStrain, weight: f64) -> Result<f64, String> {
+        if !(0.0..=1.0).contains(&weight) {
+            return Err("Blend weight must be within [0, 1]".to_string());
         }
         
-        // Calculate recursively with cannabis enhancement
-        let result = if n <= 1 {
-            n as u128
-        } else {
-            let fib1 = self.plant_spirit_fibonacci(n - 1)?;
-            let fib2 = self.plant_spirit_fibonacci(n - 2)?;
-            
-            // Apply strain-specific algorithmic variation
-            let base_result = fib1.saturating_add(fib2);
-            let enhanced_result = (base_result as f64 * self.strain_multiplier) as u128;
-            
-            enhanced_result
-        };
-        
-        // Cache the result (thread-safe)
-        {
-            let mut cache = self.cache.lock().unwrap();
-            cache.insert(n, result);
-        }
+        let (multiplier_a, _, _) = a.characteristics();
+        let (multiplier_b, _, _) = b.characteristics();
         
-        Ok(result)
+        Ok(multiplier_a * (1.0 - weight) + multiplier_b * weight)
     }
     
-    /// Generate Fibonacci sequence up to n terms
-    /// 
-    /// Demonstrates Rust's iterator patterns and error handling
-    /// while creating cannabis-enhanced mathematical sequences.
-    pub fn generate_sequence(&self, count: usize) -> Result<Vec<u128>, String> {
-        let mut sequence = Vec::with_capacity(count);
-        
-        for i in 0..count {
-            let fib_value = self.plant_spirit_fibonacci(i as u64)?;
-            sequence.push(fib_value);
+    /// Classify the multiplier as >1 (Faster), ==1 (Baseline) or <1 (Slower)
+    pub fn speed_factor_category(&self) -> SpeedCategory {
+        let (numerator, denominator) = self.multiplier_fraction();
+        match numerator.cmp(&denominator) {
+            std::cmp::Ordering::Greater => SpeedCategory::Faster,
+            std::cmp::Ordering::Equal => SpeedCategory::Baseline,
+            std::cmp::Ordering::Less => SpeedCategory::Slower,
         }
-        
-        Ok(sequence)
     }
     
-    /// Parallel Fibonacci computation using Rust's fearless concurrency
+    /// Highest index a new calculator memoizes up front
     /// 
-    /// Demonstrates safe concurrent programming with shared state
-    /// and cannabis-enhanced performance analysis.
-    pub fn parallel_fibonacci_range(&self, start: u64, end: u64) -> Result<HashMap<u64, u128>, String> {
-        if end <= start {
-            return Err("Invalid range: end must be greater than start".to_string());
-        }
-        
-        let results = Arc::new(Mutex::new(HashMap::new()));
-        let mut handles = Vec::new();
-        
-        // Spawn worker threads for concurrent computation
-        for chunk_start in (start..end).step_by(10) {
-            let chunk_end = std::cmp::min(chunk_start + 10, end);
-            let calculator = self.clone();
-            let results_clone = Arc::clone(&results);
-            
-            let handle = thread::spawn(move || {
-                for n in chunk_start..chunk_end {
-                    if let Ok(value) = calculator.plant_spirit_fibonacci(n) {
-                        let mut results = results_clone.lock().unwrap();
-                        results.insert(n, value);
-                    }
-                }
-            });
-            
-            handles.push(handle);
-        }
-        
-        // Wait for all threads to complete
-        for handle in handles {
-            handle.join().map_err(|_| "Thread panic during computation")?;
+    /// Indica's "deep caching" prewarms F(0)..=F(30) on construction;
+    /// Sativa and Hybrid stay lazy and hold only the two seeds.
+    pub fn prewarm_depth(&self) -> u64 {
+        match self {
+            CannabisStrain::Indica => 30,
+            CannabisStrain::Sativa | CannabisStrain::Hybrid => 1,
         }
-        
-        let final_results = results.lock().unwrap().clone();
-        Ok(final_results)
     }
     
-    /// Analyze golden ratio convergence with cannabis-enhanced precision
+    /// Largest index the strain's enhanced sequence reaches without overflow
     /// 
-    /// Demonstrates Rust's numeric types and floating-point precision
-    /// while exploring mathematical properties with plant spirit insights.
-    pub fn golden_ratio_analysis(&self, terms: usize) -> Result<Vec<f64>, String> {
-        let sequence = self.generate_sequence(terms)?;
-        let mut ratios = Vec::new();
-        
-        for i in 1..sequence.len() {
-            if sequence[i-1] > 0 {
-                let ratio = sequence[i] as f64 / sequence[i-1] as f64;
-                ratios.push(ratio);
-            }
+    /// Known without building a calculator, e.g. for per-strain slider
+    /// bounds. Sativa's 6/5 boost overflows u128 at F(147); Indica never
+    /// outgrows the classic limit of F(186).
+    pub fn safe_max_index(&self) -> u64 {
+        match self {
+            CannabisStrain::Sativa => 146,
+            CannabisStrain::Indica | CannabisStrain::Hybrid => RandyCannabisFibonacci::MAX_U128_INDEX,
         }
-        
-        Ok(ratios)
     }
     
-    /// Cannabis-enhanced performance benchmarking
+    /// Exact strain multiplier as a (numerator, denominator) fraction
     /// 
-    /// Demonstrates Rust's timing capabilities and performance measurement
-    /// with strain-specific algorithmic analysis.
-    pub fn performance_benchmark(&self, max_n: u64) -> Result<Vec<(u64, Duration)>, String> {
-        let mut benchmarks = Vec::new();
-        
-        for n in (1..=max_n).step_by(5) {
-            let start_time = Instant::now();
-            let _ = self.plant_spirit_fibonacci(n)?;
-            let duration = start_time.elapsed();
-            
-            benchmarks.push((n, duration));
+    /// Sativa = 6/5, Indica = 4/5, Hybrid = 1/1. Applying these with
+    /// integer arithmetic keeps the enhanced sequence exactly reproducible,
+    /// where `value as f64` would silently round anything above 2^53.
+    fn multiplier_fraction(&self) -> (u128, u128) {
+        match self {
+            CannabisStrain::Sativa => Sativa::MULTIPLIER,
+            CannabisStrain::Indica => Indica::MULTIPLIER,
+            CannabisStrain::Hybrid => Hybrid::MULTIPLIER,
         }
-        
-        Ok(benchmarks)
     }
 }
 
-/// Randy's Cannabis-Enhanced Fibonacci Iterator
+impl std::str::FromStr for CannabisStrain {
+    type Err = String;
+    
+    /// Case-insensitive strain name, e.g. "sativa" or "Hybrid"
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        CannabisStrain::ALL
+            .into_iter()
+            .find(|strain| format!("{:?}", strain).eq_ignore_ascii_case(name))
+            .ok_or_else(|| format!("Unknown strain '{}' (expected sativa, indica or hybrid)", name))
+    }
+}
+
+/// How strong a computed value "hits", from smallest to largest magnitude
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum IntensityTier {
+    Microdose,
+    Mellow,
+    Elevated,
+    Intense,
+    Transcendent,
+}
+
+/// Presentational flavor text for a computed value
 /// 
-/// Demonstrates Rust's iterator traits and lazy evaluation
-/// with infinite cannabis-enhanced mathematical sequences.
-pub struct CannabisFibonacciIterator {
-    current: u128,
-    next: u128,
-    strain: CannabisStrain,
-    count: usize,
+/// Purely for the CLI's personality; nothing in the math depends on it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EffectProfile {
+    pub tier: IntensityTier,
+    pub description: &'static str,
 }
 
-impl CannabisFibonacciIterator {
-    pub fn new(strain: CannabisStrain) -> Self {
-        CannabisFibonacciIterator {
-            current: 0,
-            next: 1,
-            strain,
-            count: 0,
-        }
-    }
+/// Interchangeable ways to compute F(n), picked at runtime via `compute`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Algorithm {
+    /// Memoized top-down recursion
+    Recursive,
+    /// Bottom-up loop with two rolling variables
+    Iterative,
+    /// O(log n) doubling identities
+    FastDoubling,
+    /// O(log n) powers of [[1, 1], [1, 0]]
+    Matrix,
 }
 
-impl Iterator for CannabisFibonacciIterator {
-    type Item = u128;
+impl Algorithm {
+    /// Every algorithm, in declaration order
+    pub const ALL: [Algorithm; 4] = [Algorithm::Recursive, Algorithm::Iterative, Algorithm::FastDoubling, Algorithm::Matrix];
     
-    fn next(&mut self) -> Option<Self::Item> {
-        let result = self.current;
-        
-        // Apply strain-specific enhancement
-        let (multiplier, _, _) = self.strain.characteristics();
-        let enhanced_next = (self.next as f64 * multiplier) as u128;
-        
-        self.current = self.next;
-        self.next = result.saturating_add(enhanced_next);
-        self.count += 1;
-        
-        // Prevent overflow by limiting iterations
-        if self.count > 100 || result > u128::MAX / 2 {
-            None
-        } else {
-            Some(result)
+    /// Human-readable name for reports and menus
+    pub fn name(&self) -> &'static str {
+        match self {
+            Algorithm::Recursive => "Recursive (memoized)",
+            Algorithm::Iterative => "Iterative",
+            Algorithm::FastDoubling => "Fast doubling",
+            Algorithm::Matrix => "Matrix exponentiation",
         }
     }
 }
 
-/// Randy's Cannabis-Enhanced Educational Demo
+/// Outcome of a cancellable parallel computation
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ParallelStatus {
+    /// Every index in the range was processed
+    Completed,
+    /// The cancel flag was set; results are partial
+    Cancelled,
+}
+
+/// Parallel results with optional lock-contention measurement
+#[derive(Debug, Clone)]
+pub struct ParallelReport {
+    pub results: HashMap<u64, u128>,
+    /// Total time workers spent acquiring the shared cache lock,
+    /// or `None` when contention measurement was switched off
+    pub lock_wait: Option<Duration>,
+}
+
+/// Typed view of `performance_benchmark` timings with aggregation helpers
 /// 
-/// Interactive demonstration of Rust features with cannabis-enhanced
-/// Fibonacci computation and plant spirit programming philosophy.
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    println!("\n🦀 RANDY'S CANNABIS-ENHANCED RUST FIBONACCI 🦀");
-    println!("    SYSTEMS PROGRAMMING WITH PLANT SPIRIT SAFETY");
-    println!("    FEARLESS CONCURRENCY AND MEMORY SAFETY");
-    println!();
+/// With the `serde` feature each duration is stored as whole nanoseconds,
+/// so saved runs can be compared across sessions.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BenchmarkResult {
+    /// `(n, duration)` samples in the order they were measured
+    #[cfg_attr(feature = "serde", serde(with = "sample_nanos"))]
+    pub samples: Vec<(u64, Duration)>,
+}
+
+/// Serde adapter writing `(n, Duration)` samples as `(n, nanoseconds)`
+#[cfg(feature = "serde")]
+mod sample_nanos {
+    use serde::{ser, Deserialize, Deserializer, Serialize, Serializer};
+    use std::time::Duration;
     
-    loop {
-        println!("Randy's Rust Programming Menu:");
-        println!("===============================");
-        println!("1. Cannabis-Enhanced Single Fibonacci");
-        println!("2. Generate Fibonacci Sequence");
-        println!("3. Parallel Fibonacci Computation");
-        println!("4. Golden Ratio Convergence Analysis");
-        println!("5. Cannabis Strain Performance Comparison");
-        println!("6. Rust Educational Wisdom");
-        println!("7. Exit to Terminal");
-        println!();
+    pub fn serialize<S: Serializer>(samples: &[(u64, Duration)], serializer: S) -> Result<S::Ok, S::Error> {
+        samples
+            .iter()
+            .map(|&(n, duration)| {
+                // u64 nanoseconds cover about 584 years, far beyond any benchmark
+                u64::try_from(duration.as_nanos())
+                    .map(|nanos| (n, nanos))
+                    .map_err(|_| <S::Error as ser::Error>::custom(format!("duration for F({}) exceeds u64 nanoseconds", n)))
+            })
+            .collect::<Result<Vec<_>, _>>()?
+            .serialize(serializer)
+    }
+    
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<(u64, Duration)>, D::Error> {
+        let raw = Vec::<(u64, u64)>::deserialize(deserializer)?;
+        Ok(raw.into_iter().map(|(n, nanos)| (n, Duration::from_nanos(nanos))).collect())
+    }
+}
+
+impl BenchmarkResult {
+    /// Sample that took the longest, if any
+    pub fn slowest(&self) -> Option<(u64, Duration)> {
+        self.samples.iter().copied().max_by_key(|&(_, duration)| duration)
+    }
+    
+    /// Sample that finished the quickest, if any
+    pub fn fastest(&self) -> Option<(u64, Duration)> {
+        self.samples.iter().copied().min_by_key(|&(_, duration)| duration)
+    }
+    
+    /// Sum of every sample's duration
+    pub fn total(&self) -> Duration {
+        self.samples.iter().map(|&(_, duration)| duration).sum()
+    }
+    
+    /// Mean duration, or `None` when there are no samples
+    pub fn average(&self) -> Option<Duration> {
+        let count = u32::try_from(self.samples.len()).ok().filter(|&count| count > 0)?;
+        Some(self.total() / count)
+    }
+}
+
+impl From<Vec<(u64, Duration)>> for BenchmarkResult {
+    fn from(samples: Vec<(u64, Duration)>) -> Self {
+        BenchmarkResult { samples }
+    }
+}
+
+/// Typed errors for Fibonacci computation
+/// 
+/// Converts into `String` so it composes with the string-based
+/// `Result` signatures used throughout the calculator.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FibonacciError {
+    /// F(requested) does not fit in u128; F(max) is the furthest that does
+    Overflow { requested: u64, max: u64 },
+    /// An intermediate total or conversion exceeded its integer type
+    ArithmeticOverflow,
+    /// Computation exceeded its time budget
+    Timeout(Duration),
+    /// Custom seeds cannot produce a valid ascending sequence
+    InvalidSeeds { first: u128, second: u128 },
+    /// Output buffer cannot hold the requested data
+    BufferTooSmall { needed: usize, available: usize },
+    /// A pre-built cache entry contradicts the seeds or the recurrence
+    InvalidCache { index: u64 },
+    /// A worker thread or blocking task panicked before returning
+    WorkerPanicked,
+    /// Writing output to an I/O sink failed
+    Io(std::io::ErrorKind),
+    /// Residues mod 0 are undefined
+    ZeroModulus,
+}
+
+impl std::fmt::Display for FibonacciError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FibonacciError::Overflow { requested, max } => {
+                write!(f, "Fibonacci overflow: F({}) exceeds u128 capacity; max is F({})", requested, max)
+            }
+            FibonacciError::ArithmeticOverflow => write!(f, "Arithmetic overflow: Result would exceed integer capacity"),
+            FibonacciError::Timeout(limit) => write!(f, "Fibonacci computation exceeded time limit of {:?}", limit),
+            FibonacciError::InvalidSeeds { first, second } => {
+                write!(f, "Invalid seeds ({}, {}): need first <= second, second > 0 and no overflow", first, second)
+            }
+            FibonacciError::BufferTooSmall { needed, available } => {
+                write!(f, "Buffer too small: need {} bytes, have {}", needed, available)
+            }
+            FibonacciError::InvalidCache { index } => write!(f, "Invalid cache entry for F({})", index),
+            FibonacciError::WorkerPanicked => write!(f, "Worker panicked during computation"),
+            FibonacciError::Io(kind) => write!(f, "I/O error while writing output: {}", kind),
+            FibonacciError::ZeroModulus => write!(f, "Modulus must be at least 1"),
+        }
+    }
+}
+
+impl std::error::Error for FibonacciError {}
+
+impl From<std::io::Error> for FibonacciError {
+    fn from(error: std::io::Error) -> Self {
+        FibonacciError::Io(error.kind())
+    }
+}
+
+impl FibonacciError {
+    /// Overflow of F(requested) against the u128 boundary, F(186)
+    pub fn overflow(requested: u64) -> Self {
+        FibonacciError::Overflow { requested, max: RandyCannabisFibonacci::MAX_U128_INDEX }
+    }
+    
+    /// Re-attribute an overflow to the index the caller originally asked for
+    fn for_request(self, requested: u64) -> Self {
+        match self {
+            FibonacciError::Overflow { max, .. } => FibonacciError::Overflow { requested, max },
+            other => other,
+        }
+    }
+}
+
+impl From<FibonacciError> for String {
+    fn from(error: FibonacciError) -> Self {
+        error.to_string()
+    }
+}
+
+impl RandyCannabisFibonacci {
+    /// Largest index whose true Fibonacci value fits in a u128
+    /// 
+    /// F(186) ≈ 3.33e38 fits; F(187) exceeds u128::MAX ≈ 3.40e38.
+    pub const MAX_U128_INDEX: u64 = 186;
+    
+    /// Largest order accepted by `fibonacci_word` (a word of F(33) ≈ 3.5M chars)
+    pub const MAX_WORD_ORDER: u64 = 32;
+    
+    /// Line segments used to approximate each quarter-circle of the golden spiral
+    pub const SPIRAL_SEGMENTS_PER_ARC: usize = 16;
+    
+    /// Indices handed to each worker thread by the parallel paths
+    pub const DEFAULT_CHUNK_SIZE: u64 = 10;
+    
+    /// Create new cannabis-enhanced Fibonacci calculator
+    /// 
+    /// # Arguments
+    /// * `strain` - Cannabis strain for algorithmic personality
+    /// 
+    /// # Examples
+    /// ```
+    /// # use randy_rust_fibonacci::{CannabisStrain, RandyCannabisFibonacci};
+    /// let calculator = RandyCannabisFibonacci::new(CannabisStrain::Sativa);
+    /// println!("Fibonacci calculator ready with {} enhancement!", calculator.strain_name());
+    /// ```
+    pub fn new(strain: CannabisStrain) -> Self {
+        Self::with_backend(strain, MutexCache::default())
+    }
+    
+    /// Process-wide Hybrid calculator, created on first use
+    /// 
+    /// Lets scripts make repeated top-level calls against one warm cache
+    /// without passing an instance around. Backed by a `OnceLock`, so
+    /// every thread sees the same instance.
+    pub fn shared() -> &'static RandyCannabisFibonacci {
+        static SHARED: OnceLock<RandyCannabisFibonacci> = OnceLock::new();
+        SHARED.get_or_init(|| Self::new(CannabisStrain::Hybrid))
+    }
+    
+    /// Create a calculator whose cache never grows past `max` entries
+    /// 
+    /// For memory-constrained environments. Once the cache is full, misses
+    /// are answered by an uncached forward pass instead of being stored, so
+    /// memory stays bounded for any index. This is a hard stop, not LRU:
+    /// existing entries are never evicted. The two seeds are always kept,
+    /// so caps below 2 act like 2, and Indica's prewarm fills only up to
+    /// the cap. Concurrent writers may each overshoot by one entry.
+    pub fn with_max_cache_entries(strain: CannabisStrain, max: usize) -> Self {
+        let mut calculator = Self::unwarmed(strain, MutexCache::default());
+        calculator.max_cache_entries = Some(max);
         
-        print!("Enter choice (1-7): ");
-        io::stdout().flush()?;
+        let depth = strain.prewarm_depth();
+        if depth > 1 {
+            let _ = calculator.fill_cache_to(depth);
+        }
         
-        let mut input = String::new();
-        io::stdin().read_line(&mut input)?;
+        calculator
+    }
+    
+    /// Create a calculator with a custom (e.g. blended) strain multiplier
+    /// 
+    /// The multiplier is stored as an exact fraction over 1000, so the
+    /// enhanced sequence stays integer-valued and reproducible.
+    pub fn with_multiplier(multiplier: f64) -> Result<Self, String> {
+        if !multiplier.is_finite() || multiplier <= 0.0 {
+            return Err("Custom multiplier must be a positive finite number".to_string());
+        }
         
-        match input.trim() {
-            "1" => single_fibonacci_demo()?,
-            "2" => sequence_generation_demo()?,
-            "3" => parallel_computation_demo()?,
-            "4" => golden_ratio_demo()?,
-            "5" => strain_comparison_demo()?,
-            "6" => display_rust_wisdom(),
-            "7" => {
-                println!("Disconnecting from Rust compiler...");
-                println!("May the borrow checker guide your memory safety journey! 🦀");
-                break;
+        let numerator = (multiplier * 1000.0).round() as u128;
+        if numerator == 0 {
+            return Err("Custom multiplier is too small to represent".to_string());
+        }
+        
+        let mut calculator = Self::new(CannabisStrain::Hybrid);
+        calculator.strain_ratio = (numerator, 1000);
+        calculator.classic_sequence = numerator == 1000;
+        calculator.strain_name = format!("Custom({:.3})", multiplier);
+        
+        Ok(calculator)
+    }
+    
+    /// Create a calculator over a generalized sequence with custom seeds
+    /// 
+    /// F(0) = `first` and F(1) = `second`, then the usual recurrence.
+    /// Seeds must produce a valid ascending sequence:
+    /// * `first <= second` (so the sequence never decreases)
+    /// * `second > 0` (otherwise every term is zero)
+    /// * `first + second` must fit in a u128
+    pub fn with_seeds(strain: CannabisStrain, first: u128, second: u128) -> Result<Self, FibonacciError> {
+        if first > second || second == 0 || first.checked_add(second).is_none() {
+            return Err(FibonacciError::InvalidSeeds { first, second });
+        }
+        
+        let mut calculator = Self::unwarmed(strain, MutexCache::default());
+        calculator.classic_sequence &= (first, second) == (0, 1);
+        calculator.cache.put(0, first);
+        calculator.cache.put(1, second);
+        
+        Ok(calculator)
+    }
+    
+    /// Warm-start a calculator from a pre-built cache
+    /// 
+    /// The cache must hold F(0) = 0 and F(1) = 1, stay within
+    /// `MAX_U128_INDEX`, and every present consecutive triple must
+    /// satisfy this strain's recurrence. Gaps are allowed; the first
+    /// offending index is reported as `InvalidCache`.
+    pub fn from_cache(strain: CannabisStrain, cache: HashMap<u64, u128>) -> Result<Self, FibonacciError> {
+        let calculator = Self::unwarmed(strain, MutexCache::default());
+        
+        for seed in [0, 1] {
+            if cache.get(&seed) != Some(&(seed as u128)) {
+                return Err(FibonacciError::InvalidCache { index: seed });
             }
-            _ => println!("Invalid choice - please enter 1-7"),
         }
         
-        println!("\nPress Enter to continue...");
-        let mut _dummy = String::new();
-        io::stdin().read_line(&mut _dummy)?;
+        let mut indices: Vec<u64> = cache.keys().copied().collect();
+        indices.sort_unstable();
+        
+        for &n in &indices {
+            if n > Self::MAX_U128_INDEX {
+                return Err(FibonacciError::InvalidCache { index: n });
+            }
+            if n < 2 {
+                continue;
+            }
+            
+            if let (Some(&previous), Some(&before)) = (cache.get(&(n - 1)), cache.get(&(n - 2))) {
+                let expected = previous
+                    .checked_add(before)
+                    .and_then(|sum| calculator.apply_strain_ratio(sum));
+                if expected != Some(cache[&n]) {
+                    return Err(FibonacciError::InvalidCache { index: n });
+                }
+            }
+        }
+        
+        for (n, value) in cache {
+            calculator.cache.put(n, value);
+        }
+        
+        Ok(calculator)
     }
     
-    Ok(())
+    /// Calls the naive exponential recursion makes for F(n): 2·F(n+1) − 1
+    /// 
+    /// Pure closed form for memoization before/after comparisons (see
+    /// `fibonacci_traced`). Saturates at `u64::MAX` from n = 92 on.
+    pub fn naive_call_count(n: u64) -> u64 {
+        n.checked_add(1)
+            .and_then(Self::nth)
+            .and_then(|next| u64::try_from(next).ok())
+            .and_then(|next| next.checked_mul(2))
+            .map_or(u64::MAX, |doubled| doubled - 1)
+    }
+    
+    /// One empty write-once slot per index from 0 to `MAX_U128_INDEX`
+    fn empty_pins() -> Arc<[OnceLock<u128>]> {
+        (0..=Self::MAX_U128_INDEX).map(|_| OnceLock::new()).collect()
+    }
+    
+    /// Number of worker threads a parallel run over `start..end` would spawn
+    /// 
+    /// Pure capacity-planning helper: one thread per `chunk_size` indices,
+    /// and zero for an empty range or a zero chunk size. Like the parallel
+    /// run itself, `end` is clamped to `MAX_U128_INDEX + 1`, since no
+    /// thread is spawned for indices that can only overflow.
+    pub fn planned_thread_count(start: u64, end: u64, chunk_size: u64) -> usize {
+        let end = end.min(Self::MAX_U128_INDEX + 1);
+        if end <= start || chunk_size == 0 {
+            return 0;
+        }
+        (end - start).div_ceil(chunk_size) as usize
+    }
+    
+    /// Classic F(n) with no cache, no strain and no heap allocation
+    /// 
+    /// Minimal-overhead entry point for hot paths: two stack variables,
+    /// iterated n times. Returns `None` once F(n) no longer fits in u128.
+    pub fn nth(n: u64) -> Option<u128> {
+        Self::fibonacci_generic::<u128>(n)
+    }
+    
+    /// Classic F(n) over any `FibInt` type, sharing one recurrence
+    /// 
+    /// `fibonacci_generic::<u64>(n)`, `::<u128>` and (with the `bigint`
+    /// feature) `::<BigUint>` all run this loop; `None` means F(n) does
+    /// not fit in `T`.
+    pub fn fibonacci_generic<T: FibInt>(n: u64) -> Option<T> {
+        if n == 0 {
+            return Some(T::zero());
+        }
+        
+        // Stop at F(n) rather than F(n+1) so the type's last value is reachable
+        let (mut previous, mut current) = (T::zero(), T::one());
+        for _ in 1..n {
+            let next = previous.checked_add(&current)?;
+            previous = current;
+            current = next;
+        }
+        Some(current)
+    }
+    
+    /// Whether every element after the first two is the sum of the previous two
+    /// 
+    /// Checks the recurrence only, from whatever seeds the slice starts
+    /// with, so Lucas numbers pass too. Empty and one- or two-element
+    /// slices have nothing to violate and are valid.
+    pub fn is_valid_fibonacci_sequence(seq: &[u128]) -> bool {
+        seq.windows(3).all(|window| window[0].checked_add(window[1]) == Some(window[2]))
+    }
 }
 
-/// Demonstrate single Fibonacci calculation with error handling
-fn single_fibonacci_demo() -> Result<(), Box<dyn std::error::Error>> {
-    println!("\n🌿 Cannabis-Enhanced Single Fibonacci Calculation 🌿");
+impl<B: CacheBackend + 'static> RandyCannabisFibonacci<B> {
+    /// Create a calculator memoizing into a custom cache backend
+    /// 
+    /// The backend is seeded with F(0) = 0 and F(1) = 1, then prewarmed
+    /// according to the strain's caching personality (see `prewarm_depth`).
+    pub fn with_backend(strain: CannabisStrain, backend: B) -> Self {
+        let calculator = Self::unwarmed(strain, backend);
+        
+        let depth = strain.prewarm_depth();
+        if depth > 1 {
+            // Well inside every strain's u128 range, so this cannot overflow
+            let _ = calculator.fill_cache_to(depth);
+        }
+        
+        calculator
+    }
     
-    print!("Enter Fibonacci position (0-186): ");
-    io::stdout().flush()?;
+    /// Calculator holding only the standard seeds, with no prewarming
+    fn unwarmed(strain: CannabisStrain, backend: B) -> Self {
+        backend.put(0, 0);
+        backend.put(1, 1);
+        
+        RandyCannabisFibonacci {
+            cache: Arc::new(backend),
+            negative_cache: Arc::new(Mutex::new(HashMap::new())),
+            negative_stats: Arc::default(),
+            strain_ratio: strain.multiplier_fraction(),
+            strain_name: format!("{:?}", strain),
+            classic_sequence: strain.multiplier_fraction() == (1, 1),
+            table_seeded_to: Arc::new(AtomicU64::new(1)),
+            lock_wait_nanos: None,
+            pinned: RandyCannabisFibonacci::empty_pins(),
+            max_cache_entries: None,
+            stats: Arc::default(),
+        }
+    }
     
-    let mut input = String::new();
-    io::stdin().read_line(&mut input)?;
+    /// The cache backend this calculator memoizes into
+    pub fn backend(&self) -> &B {
+        &self.cache
+    }
     
-    let n: u64 = input.trim().parse()
-        .map_err(|_| "Invalid number format")?;
+    /// Cannabis-enhanced memoized Fibonacci calculation
+    /// 
+    /// Demonstrates Rust's memory safety while implementing efficient
+    /// dynamic programming with strain-specific algorithmic variations.
+    /// An `Overflow` error names `n` and the furthest index that fit.
+    pub fn plant_spirit_fibonacci(&self, n: u64) -> Result<u128, FibonacciError> {
+        if n > RandyCannabisFibonacci::MAX_U128_INDEX {
+            return Err(FibonacciError::overflow(n));
+        }
+        
+        // Compile-time table answers tiny indices, touching the mutex only
+        // the first time a hit reaches past what is already seeded
+        if self.classic_sequence && n < FIB_TABLE.len() as u64 {
+            let already_seeded = self.seed_table_prefix(n);
+            self.record_lookup(already_seeded);
+            return Ok(FIB_TABLE[n as usize]);
+        }
+        
+        // Check cache first (thread-safe access)
+        let cached = self.cache_get(n);
+        self.record_lookup(cached.is_some());
+        if let Some(value) = cached {
+            return Ok(value);
+        }
+        
+        // A full capped cache cannot memoize, so avoid exponential recursion
+        if self.cache_is_full() {
+            return self.forward_pass(n);
+        }
+        
+        // Calculate recursively with cannabis enhancement
+        let result = if n <= 1 {
+            n as u128
+        } else {
+            let fib1 = self.plant_spirit_fibonacci(n - 1).map_err(|error| error.for_request(n))?;
+            let fib2 = self.plant_spirit_fibonacci(n - 2).map_err(|error| error.for_request(n))?;
+            
+            // Apply strain-specific algorithmic variation (exact fixed-point);
+            // F(n-1) just succeeded, so it is the furthest index that fits
+            fib1.checked_add(fib2)
+                .and_then(|base_result| self.apply_strain_ratio(base_result))
+                .ok_or(FibonacciError::Overflow { requested: n, max: n - 1 })?
+        };
+        
+        // Cache the result (thread-safe)
+        self.cache_put(n, result);
+        
+        Ok(result)
+    }
     
-    let calculator = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+    /// Make sure every index 0..=n is memoized, in one forward pass
+    /// 
+    /// The explicit prewarm primitive: no recursion and no values handed
+    /// back, just existing entries reused and missing ones filled from the
+    /// two before them. Errors without caching anything past the overflow
+    /// boundary. A capped cache (see `with_max_cache_entries`) stops
+    /// storing once full.
+    pub fn fill_cache_to(&self, n: u64) -> Result<(), FibonacciError> {
+        self.forward_pass(n).map(|_| ())
+    }
     
-    match calculator.plant_spirit_fibonacci(n) {
-        Ok(result) => {
-            println!("Fibonacci({}) = {}", n, result);
-            println!("Calculated with {} strain enhancement!", calculator.strain_name);
-            println!("Memory-safe computation guaranteed by Rust's borrow checker!");
+    /// Walk F(0)..=F(n), reusing cached entries and storing new ones
+    fn forward_pass(&self, n: u64) -> Result<u128, FibonacciError> {
+        if n > RandyCannabisFibonacci::MAX_U128_INDEX {
+            return Err(FibonacciError::overflow(n));
         }
-        Err(e) => println!("Calculation error: {}", e),
+        
+        let (mut two_back, mut one_back) = (0u128, 0u128);
+        for k in 0..=n {
+            let value = match self.cache_get(k) {
+                Some(value) => value,
+                None => {
+                    let value = if k <= 1 {
+                        k as u128
+                    } else {
+                        one_back
+                            .checked_add(two_back)
+                            .and_then(|sum| self.apply_strain_ratio(sum))
+                            .ok_or(FibonacciError::Overflow { requested: n, max: k - 1 })?
+                    };
+                    self.cache_put(k, value);
+                    value
+                }
+            };
+            two_back = one_back;
+            one_back = value;
+        }
+        
+        Ok(one_back)
     }
     
-    Ok(())
-}
-
-/// Demonstrate sequence generation with iterator patterns
-fn sequence_generation_demo() -> Result<(), Box<dyn std::error::Error>> {
-    println!("\n🦀 Rust Iterator Pattern Fibonacci Sequence 🦀");
+    /// Generate Fibonacci sequence up to n terms
+    /// 
+    /// Demonstrates Rust's iterator patterns and error handling
+    /// while creating cannabis-enhanced mathematical sequences.
+    pub fn generate_sequence(&self, count: usize) -> Result<Vec<u128>, String> {
+        self.generate_sequence_with_progress(count, |_| {})
+    }
     
-    print!("Enter number of terms (1-30): ");
-    io::stdout().flush()?;
+    /// `generate_sequence` that reports each finished index to `on_progress`
+    /// 
+    /// The callback runs once per term, in order, right after that term is
+    /// computed, so a UI can drive a progress bar for large counts. It is
+    /// not called for a term that overflows.
+    pub fn generate_sequence_with_progress(&self, count: usize, mut on_progress: impl FnMut(usize)) -> Result<Vec<u128>, String> {
+        // Never reserve past the overflow boundary, however large `count` is
+        let mut sequence = Vec::with_capacity(count.min(RandyCannabisFibonacci::MAX_U128_INDEX as usize + 1));
+        
+        for i in 0..count {
+            sequence.push(self.plant_spirit_fibonacci(i as u64)?);
+            on_progress(i);
+        }
+        
+        Ok(sequence)
+    }
     
-    let mut input = String::new();
-    io::stdin().read_line(&mut input)?;
+    /// `generate_sequence` plus the cache hit rate of this call alone
+    /// 
+    /// Resets the `cache_stats` counters, generates, reads them, then adds
+    /// the saved totals back, so the running statistics still include this
+    /// call. Every lookup counts, the recursion's own included: a cold run
+    /// scores well below an identical second run, which scores 1.0. The
+    /// counters are shared, so lookups made by other threads during the
+    /// call land in the same window; under contention the rate is only
+    /// approximate. No lookups (e.g. zero terms) give a rate of 0.0.
+    pub fn generate_sequence_with_hit_rate(&self, count: usize) -> Result<(Vec<u128>, f64), String> {
+        let saved = self.stats.take();
+        let sequence = self.generate_sequence(count);
+        let (hits, misses) = self.stats.take();
+        self.stats.add((saved.0 + hits, saved.1 + misses));
+        
+        let lookups = hits + misses;
+        let hit_rate = if lookups == 0 { 0.0 } else { hits as f64 / lookups as f64 };
+        Ok((sequence?, hit_rate))
+    }
     
-    let count: usize = input.trim().parse()
-        .map_err(|_| "Invalid number format")?;
+    /// Up to `count` terms, silently stopping at the overflow boundary
+    /// 
+    /// Where `generate_sequence` fails outright, this keeps every term that
+    /// fit: asking for 300 Hybrid terms returns F(0)..=F(186), all 187 of
+    /// them. Check the length if truncation matters to the caller.
+    pub fn generate_sequence_truncating(&self, count: usize) -> Vec<u128> {
+        self.try_sequence_iter().take(count).map_while(Result::ok).collect()
+    }
     
-    if count > 30 {
-        println!("Limiting to 30 terms for display purposes");
-        return Ok(());
+    /// Generate terms until `pred` holds for the latest value (inclusive)
+    /// 
+    /// A data-driven alternative to a fixed count, e.g. "stop at the first
+    /// value over 1000". Errors if the sequence overflows before `pred` is met.
+    pub fn generate_until(&self, pred: impl Fn(u128) -> bool) -> Result<Vec<u128>, String> {
+        let mut sequence = Vec::new();
+        
+        for n in 0.. {
+            let value = self.plant_spirit_fibonacci(n)?;
+            sequence.push(value);
+            if pred(value) {
+                break;
+            }
+        }
+        
+        Ok(sequence)
+    }
+    
+    /// Running totals F(0), F(0)+F(1), ... of the first `count` terms
+    /// 
+    /// Prefix sums for plotting cumulative growth; by the identity
+    /// ΣF(0..=n) = F(n+2) − 1 they trail the sequence itself by two steps.
+    pub fn cumulative_sequence(&self, count: usize) -> Result<Vec<u128>, String> {
+        let mut running_total: u128 = 0;
+        
+        self.generate_sequence(count)?
+            .into_iter()
+            .map(|value| {
+                running_total = running_total.checked_add(value).ok_or(FibonacciError::ArithmeticOverflow)?;
+                Ok(running_total)
+            })
+            .collect()
+    }
+    
+    /// Fold the first `count` terms with a caller-supplied closure
+    /// 
+    /// One call covers sums, products, maxima, or any custom aggregate,
+    /// e.g. `fold_sequence(10, 0, |acc, v| acc.max(v))`. Terms are computed
+    /// as they are folded, so an overflowing term stops the fold with `Err`.
+    pub fn fold_sequence<T>(&self, count: usize, init: T, f: impl Fn(T, u128) -> T) -> Result<T, String> {
+        (0..count as u64).try_fold(init, |acc, n| Ok(f(acc, self.plant_spirit_fibonacci(n)?)))
+    }
+    
+    /// Smallest number of terms whose running total reaches `target`
+    /// 
+    /// Inverse of `cumulative_sequence`: with totals 0, 1, 2, 4, 7, 12, ...
+    /// a target of 10 needs 6 terms. A target of 0 needs none. Errors if
+    /// the sequence overflows before the total gets there.
+    pub fn terms_for_sum(&self, target: u128) -> Result<u64, String> {
+        let mut running_total: u128 = 0;
+        let mut count = 0;
+        
+        while running_total < target {
+            let value = self.plant_spirit_fibonacci(count)?;
+            running_total = running_total.checked_add(value).ok_or(FibonacciError::ArithmeticOverflow)?;
+            count += 1;
+        }
+        
+        Ok(count)
+    }
+    
+    /// Every `stride`-th term: F(0), F(stride), F(2·stride), ...
+    /// 
+    /// Stride 3 picks out exactly the even Fibonacci numbers. Errors for a
+    /// zero stride or once an index passes the overflow boundary.
+    pub fn stride_sequence(&self, stride: u64, count: usize) -> Result<Vec<u128>, String> {
+        if stride == 0 {
+            return Err("Stride must be at least 1".to_string());
+        }
+        
+        (0..count as u64)
+            .map(|k| {
+                let n = k.checked_mul(stride).ok_or(FibonacciError::ArithmeticOverflow)?;
+                Ok(self.plant_spirit_fibonacci(n)?)
+            })
+            .collect()
+    }
+    
+    /// Parallel Fibonacci computation using Rust's fearless concurrency
+    /// 
+    /// Demonstrates safe concurrent programming with shared state
+    /// and cannabis-enhanced performance analysis.
+    pub fn parallel_fibonacci_range(&self, start: u64, end: u64) -> Result<HashMap<u64, u128>, String> {
+        self.parallel_fibonacci_range_with(start, end, RandyCannabisFibonacci::DEFAULT_CHUNK_SIZE)
+    }
+    
+    /// Parallel computation with a caller-chosen number of indices per thread
+    /// 
+    /// Spawns `planned_thread_count(start, end, chunk_size)` workers, with
+    /// `end` clamped to the overflow boundary since later indices have no value.
+    pub fn parallel_fibonacci_range_with(&self, start: u64, end: u64, chunk_size: u64) -> Result<HashMap<u64, u128>, String> {
+        let never_cancelled = Arc::new(AtomicBool::new(false));
+        let (results, _) = self.run_parallel_range(start, end, chunk_size, never_cancelled)?;
+        Ok(results)
+    }
+    
+    /// Cancellable parallel Fibonacci computation
+    /// 
+    /// Workers check the shared `cancel` flag between indices, so setting it
+    /// (e.g. from a UI stop button) stops the run promptly. Returns whatever
+    /// was computed so far together with the run's status.
+    pub fn parallel_fibonacci_range_cancellable(
+        &self,
+        start: u64,
+        end: u64,
+        cancel: Arc<AtomicBool>,
+    ) -> Result<(HashMap<u64, u128>, ParallelStatus), String> {
+        self.run_parallel_range(start, end, RandyCannabisFibonacci::DEFAULT_CHUNK_SIZE, cancel)
+    }
+    
+    /// Shared worker-pool implementation behind the parallel entry points
+    fn run_parallel_range(
+        &self,
+        start: u64,
+        end: u64,
+        chunk_size: u64,
+        cancel: Arc<AtomicBool>,
+    ) -> Result<(HashMap<u64, u128>, ParallelStatus), String> {
+        if end <= start {
+            return Err("Invalid range: end must be greater than start".to_string());
+        }
+        if chunk_size == 0 {
+            return Err("Invalid chunk size: must be at least 1".to_string());
+        }
+        
+        let results = Arc::new(Mutex::new(HashMap::new()));
+        let mut handles = Vec::new();
+        
+        // Indices past the overflow boundary can never produce a value
+        let end = end.min(RandyCannabisFibonacci::MAX_U128_INDEX + 1);
+        
+        // Spawn worker threads for concurrent computation
+        for chunk_start in (start..end).step_by(chunk_size as usize) {
+            let chunk_end = std::cmp::min(chunk_start.saturating_add(chunk_size), end);
+            let calculator = self.clone();
+            let results_clone = Arc::clone(&results);
+            let cancel_clone = Arc::clone(&cancel);
+            
+            let handle = spawn_worker(move || {
+                for n in chunk_start..chunk_end {
+                    if cancel_clone.load(Ordering::Relaxed) {
+                        break;
+                    }
+                    if let Ok(value) = calculator.plant_spirit_fibonacci(n) {
+                        let mut results = results_clone.lock().unwrap_or_else(PoisonError::into_inner);
+                        results.insert(n, value);
+                    }
+                }
+            })?;
+            
+            handles.push(handle);
+        }
+        
+        // Wait for all threads to complete
+        for handle in handles {
+            handle.join().map_err(|_| "Thread panic during computation")?;
+        }
+        
+        let final_results = results.lock().unwrap_or_else(PoisonError::into_inner).clone();
+        let status = if cancel.load(Ordering::Relaxed) {
+            ParallelStatus::Cancelled
+        } else {
+            ParallelStatus::Completed
+        };
+        
+        Ok((final_results, status))
+    }
+    
+    /// Parallel computation with a private cache per worker
+    /// 
+    /// Each worker memoizes into its own cache and returns its chunk when
+    /// done, so no lock is ever shared. Trades repeated work (every worker
+    /// recomputes the low indices) for zero contention.
+    pub fn parallel_fibonacci_threadlocal(&self, start: u64, end: u64) -> Result<HashMap<u64, u128>, String> {
+        if end <= start {
+            return Err("Invalid range: end must be greater than start".to_string());
+        }
+        
+        let mut handles = Vec::new();
+        let end = end.min(RandyCannabisFibonacci::MAX_U128_INDEX + 1);
+        
+        for chunk_start in (start..end).step_by(RandyCannabisFibonacci::DEFAULT_CHUNK_SIZE as usize) {
+            let chunk_end = std::cmp::min(chunk_start.saturating_add(RandyCannabisFibonacci::DEFAULT_CHUNK_SIZE), end);
+            let calculator = self.detached();
+            
+            let handle = spawn_worker(move || {
+                (chunk_start..chunk_end)
+                    .filter_map(|n| calculator.plant_spirit_fibonacci(n).ok().map(|value| (n, value)))
+                    .collect::<Vec<_>>()
+            })?;
+            
+            handles.push(handle);
+        }
+        
+        let mut results = HashMap::new();
+        for handle in handles {
+            let chunk = handle.join().map_err(|_| "Thread panic during computation")?;
+            results.extend(chunk);
+        }
+        
+        Ok(results)
+    }
+    
+    /// Stream F(0)..F(count-1) to a consumer over an `mpsc` channel
+    /// 
+    /// A producer thread sharing this calculator's cache sends each
+    /// `(index, value)` as soon as it is computed. The producer stops
+    /// early on overflow or once the receiver hangs up; join the handle
+    /// to learn which. Errors only if the thread cannot be spawned.
+    pub fn compute_to_channel(
+        &self,
+        count: usize,
+        tx: Sender<(u64, u128)>,
+    ) -> Result<thread::JoinHandle<Result<(), String>>, String> {
+        let calculator = self.clone();
+        
+        spawn_worker(move || {
+            for n in 0..count as u64 {
+                let value = calculator.plant_spirit_fibonacci(n)?;
+                tx.send((n, value)).map_err(|_| "Receiver disconnected before the sequence finished")?;
+            }
+            Ok(())
+        })
+    }
+    
+    /// F(n) for each requested index, in the order given
+    /// 
+    /// Shares and warms this calculator's cache; stops at the first error.
+    pub fn compute_indices(&self, indices: &[u64]) -> Result<Vec<u128>, FibonacciError> {
+        indices.iter().map(|&n| self.plant_spirit_fibonacci(n)).collect()
+    }
+    
+    /// Async analog of `compute_indices`: every index runs concurrently
+    /// 
+    /// Each computation goes to `tokio::task::spawn_blocking` (it is CPU
+    /// work, not I/O) on a clone sharing this calculator's cache, and
+    /// `join_all` awaits them together. Results keep the input order.
+    #[cfg(feature = "async")]
+    pub async fn compute_many_async(&self, indices: Vec<u64>) -> Result<Vec<u128>, FibonacciError> {
+        let tasks = indices.into_iter().map(|n| {
+            let calculator = self.clone();
+            tokio::task::spawn_blocking(move || calculator.plant_spirit_fibonacci(n))
+        });
+        
+        join_all(tasks)
+            .await
+            .into_iter()
+            .map(|joined| joined.map_err(|_| FibonacciError::WorkerPanicked)?)
+            .collect()
+    }
+    
+    /// F(n) under every strain, one thread per strain
+    /// 
+    /// Each strain gets its own fresh calculator and cache, so the
+    /// threads share nothing. Handy for side-by-side strain comparison;
+    /// errors if any strain overflows at `n`.
+    /// 
+    /// The strains do not agree past the first few terms, and cannot: the
+    /// multiplier is applied inside each strain's recurrence, so only
+    /// Hybrid (1/1) produces classic F(n). Sativa's 6/5 compounds upward
+    /// and Indica's 4/5 floors to zero after F(1) (see
+    /// `audit_strain_correctness` for where each departs from F(n)). Every
+    /// entry equals `plant_spirit_fibonacci(n)` under that strain.
+    pub fn compute_all_strains(&self, n: u64) -> Result<HashMap<CannabisStrain, u128>, String> {
+        let handles = CannabisStrain::ALL
+            .into_iter()
+            .map(|strain| {
+                spawn_worker(move || {
+                    let calculator = RandyCannabisFibonacci::new(strain);
+                    calculator.plant_spirit_fibonacci(n).map(|value| (strain, value))
+                })
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+        
+        let mut results = HashMap::new();
+        for handle in handles {
+            let (strain, value) = handle.join().map_err(|_| "Thread panic during computation")??;
+            results.insert(strain, value);
+        }
+        
+        Ok(results)
+    }
+    
+    /// Copy of this calculator with its own `MutexCache` holding only the seeds
+    fn detached(&self) -> RandyCannabisFibonacci {
+        let seeds = MutexCache::default();
+        for n in [0, 1] {
+            if let Some(value) = self.cache_get(n) {
+                seeds.put(n, value);
+            }
+        }
+        
+        RandyCannabisFibonacci {
+            cache: Arc::new(seeds),
+            negative_cache: Arc::new(Mutex::new(HashMap::new())),
+            negative_stats: Arc::default(),
+            strain_ratio: self.strain_ratio,
+            strain_name: self.strain_name.clone(),
+            classic_sequence: self.classic_sequence,
+            table_seeded_to: Arc::new(AtomicU64::new(1)),
+            lock_wait_nanos: None,
+            pinned: RandyCannabisFibonacci::empty_pins(),
+            max_cache_entries: self.max_cache_entries,
+            stats: Arc::default(),
+        }
+    }
+    
+    /// Parallel computation with optional lock-contention instrumentation
+    /// 
+    /// Demonstrates the hidden cost of the shared-cache design: with
+    /// `measure_contention` on, every cache lock acquisition is timed and
+    /// the total wait across all workers is returned in the report.
+    pub fn parallel_fibonacci_range_instrumented(
+        &self,
+        start: u64,
+        end: u64,
+        measure_contention: bool,
+    ) -> Result<ParallelReport, String> {
+        let mut calculator = self.clone();
+        calculator.lock_wait_nanos = measure_contention.then(|| Arc::new(AtomicU64::new(0)));
+        
+        let results = calculator.parallel_fibonacci_range(start, end)?;
+        let lock_wait = calculator
+            .lock_wait_nanos
+            .map(|total| Duration::from_nanos(total.load(Ordering::Relaxed)));
+        
+        Ok(ParallelReport { results, lock_wait })
+    }
+    
+    /// Copy `FIB_TABLE[0..=n]` into the cache unless a previous hit already did
+    /// 
+    /// Leaves `cached_indices` exactly as the recursion would have, so the
+    /// table changes speed but not what callers observe. Returns whether
+    /// the prefix was already seeded, i.e. whether this was a cache hit.
+    fn seed_table_prefix(&self, n: u64) -> bool {
+        if n <= self.table_seeded_to.load(Ordering::Acquire) {
+            return true;
+        }
+        
+        for k in 0..=n {
+            if self.cache_get(k).is_none() {
+                self.cache_put(k, FIB_TABLE[k as usize]);
+            }
+        }
+        self.table_seeded_to.fetch_max(n, Ordering::Release);
+        false
+    }
+    
+    /// Count one `plant_spirit_fibonacci` lookup as a hit or a miss
+    fn record_lookup(&self, hit: bool) {
+        let counter = if hit { &self.stats.hits } else { &self.stats.misses };
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+    
+    /// Read from the cache backend, timing lock acquisition when instrumentation is on
+    fn cache_get(&self, n: u64) -> Option<u128> {
+        match &self.lock_wait_nanos {
+            Some(wait_nanos) => self.cache.get_timed(n, wait_nanos),
+            None => self.cache.get(n),
+        }
+    }
+    
+    /// Write to the cache backend, timing lock acquisition when instrumentation is on
+    /// 
+    /// Silently skipped once a capped cache is full.
+    fn cache_put(&self, n: u64, value: u128) {
+        if self.cache_is_full() {
+            return;
+        }
+        match &self.lock_wait_nanos {
+            Some(wait_nanos) => self.cache.put_timed(n, value, wait_nanos),
+            None => self.cache.put(n, value),
+        }
+    }
+    
+    /// Whether a `max_cache_entries` cap has been reached
+    fn cache_is_full(&self) -> bool {
+        self.max_cache_entries.is_some_and(|max| self.cache.len() >= max)
+    }
+    
+    /// Analyze golden ratio convergence with cannabis-enhanced precision
+    /// 
+    /// Demonstrates Rust's numeric types and floating-point precision
+    /// while exploring mathematical properties with plant spirit insights.
+    pub fn golden_ratio_analysis(&self, terms: usize) -> Result<Vec<f64>, String> {
+        let sequence = self.generate_sequence(terms)?;
+        let mut ratios = Vec::new();
+        
+        for i in 1..sequence.len() {
+            if sequence[i-1] > 0 {
+                let ratio = sequence[i] as f64 / sequence[i-1] as f64;
+                ratios.push(ratio);
+            }
+        }
+        
+        Ok(ratios)
+    }
+    
+    /// Golden-ratio convergence formatted to `decimal_places` digits
+    /// 
+    /// Formats the full-precision `f64` ratios from `golden_ratio_analysis`
+    /// exactly once, so nothing is truncated before the caller's precision.
+    /// Precision is limited to 100 places, far past the ~17 significant
+    /// digits an f64 actually holds.
+    pub fn golden_ratio_analysis_precise(&self, terms: usize, decimal_places: usize) -> Result<Vec<String>, String> {
+        const MAX_DECIMAL_PLACES: usize = 100;
+        
+        if decimal_places > MAX_DECIMAL_PLACES {
+            return Err(format!("Precision is limited to {} decimal places", MAX_DECIMAL_PLACES));
+        }
+        
+        let ratios = self.golden_ratio_analysis(terms)?;
+        
+        Ok(ratios
+            .iter()
+            .map(|ratio| format!("{:.*}", decimal_places, ratio))
+            .collect())
+    }
+    
+    /// Partial sum of reciprocals 1/F(1) + ... + 1/F(terms)
+    /// 
+    /// F(0) = 0 is skipped to avoid dividing by zero. For the classic
+    /// sequence this approaches the reciprocal Fibonacci constant ψ ≈ 3.3598.
+    pub fn reciprocal_sum(&self, terms: usize) -> Result<f64, String> {
+        let mut total = 0.0;
+        
+        for n in 1..=terms as u64 {
+            total += 1.0 / self.plant_spirit_fibonacci(n)? as f64;
+        }
+        
+        Ok(total)
+    }
+    
+    /// Lazy successive ratios F(n)/F(n−1), skipping a zero denominator
+    /// 
+    /// Allocation-free companion to `golden_ratio_analysis`, so callers can
+    /// stop with `take_while` as soon as convergence reaches a tolerance.
+    /// Ends at the overflow boundary of the underlying sequence.
+    pub fn ratio_iter(&self) -> impl Iterator<Item = f64> + '_ {
+        self.into_iter()
+            .zip(self.into_iter().skip(1))
+            .filter(|&(previous, _)| previous > 0)
+            .map(|(previous, current)| current as f64 / previous as f64)
+    }
+    
+    /// Terms needed before F(n)/F(n−1) is within `tolerance` of φ
+    /// 
+    /// Counts terms from F(0), so the answer is n + 1 for the first n
+    /// whose ratio error drops below the tolerance. Tolerances below
+    /// `f64::EPSILON` cannot be resolved in f64 and are rejected; a strain
+    /// whose ratios settle away from φ errors at the overflow boundary.
+    pub fn terms_to_converge(&self, tolerance: f64) -> Result<usize, String> {
+        if !tolerance.is_finite() || tolerance < f64::EPSILON {
+            return Err(format!("Tolerance must be a finite value >= {:e}", f64::EPSILON));
+        }
+        
+        let phi = (1.0 + 5.0_f64.sqrt()) / 2.0;
+        
+        // ratio_iter starts at F(2)/F(1), so the i-th ratio ends at term i + 2
+        self.ratio_iter()
+            .position(|ratio| (ratio - phi).abs() < tolerance)
+            .map(|position| position + 3)
+            .ok_or_else(|| "Ratios never came within tolerance of the golden ratio".to_string())
+    }
+    
+    /// Growth ratio between the last two of `terms` strain-enhanced values
+    /// 
+    /// Runs the compounding recurrence x(n) = m·(x(n-1) + x(n-2)) in f64,
+    /// tracking only the ratio r(n) = m·(1 + 1/r(n-1)) so it never
+    /// overflows. Real arithmetic matters here: integer flooring collapses
+    /// Indica's u128 sequence to zeros after F(1), hiding its personality.
+    /// Converges to (m + √(m² + 4m))/2: ≈1.849 for Sativa, φ for Hybrid,
+    /// ≈1.380 for Indica. NaN for fewer than three terms.
+    pub fn enhanced_growth_ratio(&self, terms: usize) -> f64 {
+        if terms < 3 {
+            return f64::NAN;
+        }
+        
+        let multiplier = self.strain_ratio.0 as f64 / self.strain_ratio.1 as f64;
+        let mut inverse_ratio = 0.0; // x(0)/x(1)
+        let mut ratio = f64::NAN;
+        
+        for _ in 2..terms {
+            ratio = multiplier * (1.0 + inverse_ratio);
+            inverse_ratio = 1.0 / ratio;
+        }
+        
+        ratio
+    }
+    
+    /// Rigorous floating-point bounds on F(n) from Binet's formula
+    /// 
+    /// Evaluates F(n) = (φⁿ − ψⁿ)/√5 in interval arithmetic: every rounded
+    /// operation is nudged one ulp outward with `next_down`/`next_up`, so
+    /// the true integer always lies in `[lower, upper]`, even once it no
+    /// longer fits in f64's 53-bit mantissa. The upper bound becomes
+    /// infinite for huge n. Binet describes pure Fibonacci, so the strain
+    /// plays no part here.
+    pub fn binet_interval(&self, n: u64) -> (f64, f64) {
+        /// Interval power by squaring, rounding each product outward
+        fn pow_outward((mut base_lo, mut base_hi): (f64, f64), mut exponent: u64) -> (f64, f64) {
+            let (mut lo, mut hi) = (1.0_f64, 1.0_f64);
+            while exponent > 0 {
+                if exponent & 1 == 1 {
+                    lo = (lo * base_lo).next_down();
+                    hi = (hi * base_hi).next_up();
+                }
+                base_lo = (base_lo * base_lo).next_down();
+                base_hi = (base_hi * base_hi).next_up();
+                exponent >>= 1;
+            }
+            (lo.max(0.0), hi)
+        }
+        
+        // sqrt is correctly rounded, so one ulp either side brackets √5
+        let root = 5.0_f64.sqrt();
+        let sqrt5 = (root.next_down(), root.next_up());
+        let phi = ((1.0 + sqrt5.0).next_down() / 2.0, (1.0 + sqrt5.1).next_up() / 2.0);
+        let psi_abs = ((sqrt5.0 - 1.0).next_down() / 2.0, (sqrt5.1 - 1.0).next_up() / 2.0);
+        
+        let phi_pow = pow_outward(phi, n);
+        let psi_pow = pow_outward(psi_abs, n);
+        let major = ((phi_pow.0 / sqrt5.1).next_down(), (phi_pow.1 / sqrt5.0).next_up());
+        let minor = ((psi_pow.0 / sqrt5.1).next_down().max(0.0), (psi_pow.1 / sqrt5.0).next_up());
+        
+        // ψ is negative, so ψⁿ/√5 is subtracted for even n and added for odd n
+        let (lower, upper) = if n.is_multiple_of(2) {
+            ((major.0 - minor.1).next_down(), (major.1 - minor.0).next_up())
+        } else {
+            ((major.0 + minor.0).next_down(), (major.1 + minor.1).next_up())
+        };
+        
+        (lower.max(0.0), upper)
+    }
+    
+    /// Cannabis-enhanced performance benchmarking
+    /// 
+    /// Demonstrates Rust's timing capabilities and performance measurement
+    /// with strain-specific algorithmic analysis.
+    pub fn performance_benchmark(&self, max_n: u64) -> Result<Vec<(u64, Duration)>, String> {
+        let mut benchmarks = Vec::new();
+        
+        for n in (1..=max_n).step_by(5) {
+            let start_time = Instant::now();
+            let _ = self.plant_spirit_fibonacci(n)?;
+            let duration = start_time.elapsed();
+            
+            benchmarks.push((n, duration));
+        }
+        
+        Ok(benchmarks)
+    }
+    
+    /// `performance_benchmark` wrapped in a `BenchmarkResult` for analysis
+    pub fn benchmark_result(&self, max_n: u64) -> Result<BenchmarkResult, String> {
+        self.performance_benchmark(max_n).map(BenchmarkResult::from)
+    }
+    
+    /// Explain the memoized recursion as an indented call tree
+    /// 
+    /// Demonstrates where memoization short-circuits the exponential
+    /// recursion: repeated subproblems are marked `[cache hit]` instead
+    /// of being expanded again. Limited to n <= 10 to keep the tree readable.
+    pub fn fibonacci_explain(&self, n: u64) -> Result<String, String> {
+        if n > 10 {
+            return Err("Explain mode is limited to n <= 10".to_string());
+        }
+        
+        let mut trace = String::new();
+        let mut seen = HashSet::new();
+        self.explain_node(n, 0, &mut seen, &mut trace)?;
+        
+        Ok(trace)
+    }
+    
+    /// Recursive helper for `fibonacci_explain`
+    fn explain_node(
+        &self,
+        n: u64,
+        depth: usize,
+        seen: &mut HashSet<u64>,
+        trace: &mut String,
+    ) -> Result<u128, String> {
+        let indent = "  ".repeat(depth);
+        
+        if n <= 1 {
+            trace.push_str(&format!("{}F({}) = {} [base case]\n", indent, n, n));
+            return Ok(n as u128);
+        }
+        
+        if seen.contains(&n) {
+            let value = self.plant_spirit_fibonacci(n)?;
+            trace.push_str(&format!("{}F({}) = {} [cache hit]\n", indent, n, value));
+            return Ok(value);
+        }
+        
+        trace.push_str(&format!("{}F({}) = F({}) + F({})\n", indent, n, n - 1, n - 2));
+        self.explain_node(n - 1, depth + 1, seen, trace)?;
+        self.explain_node(n - 2, depth + 1, seen, trace)?;
+        
+        let value = self.plant_spirit_fibonacci(n)?;
+        trace.push_str(&format!("{}F({}) = {} [memoized]\n", indent, n, value));
+        seen.insert(n);
+        
+        Ok(value)
+    }
+    
+    /// Graphviz DOT of the naive (non-memoized) recursion tree for F(n)
+    /// 
+    /// Every call is its own node, so the 2·F(n+1) − 1 nodes make the
+    /// exponential blowup visible when rendered with `dot -Tsvg`. Limited
+    /// to n <= 10 like `fibonacci_explain`; base cases are drawn as boxes.
+    pub fn recursion_dot(&self, n: u64) -> Result<String, String> {
+        if n > 10 {
+            return Err("Recursion DOT output is limited to n <= 10".to_string());
+        }
+        
+        let mut dot = format!("digraph fibonacci_{} {{\n    node [shape=ellipse];\n", n);
+        let mut next_id = 0;
+        Self::dot_node(n, &mut next_id, &mut dot);
+        dot.push_str("}\n");
+        
+        Ok(dot)
+    }
+    
+    /// Recursive helper for `recursion_dot`; returns the node's id
+    fn dot_node(n: u64, next_id: &mut usize, dot: &mut String) -> usize {
+        let id = *next_id;
+        *next_id += 1;
+        
+        if n <= 1 {
+            dot.push_str(&format!("    n{} [label=\"F({})\", shape=box];\n", id, n));
+            return id;
+        }
+        
+        dot.push_str(&format!("    n{} [label=\"F({})\"];\n", id, n));
+        for child in [n - 1, n - 2] {
+            let child_id = Self::dot_node(child, next_id, dot);
+            dot.push_str(&format!("    n{} -> n{};\n", id, child_id));
+        }
+        
+        id
+    }
+    
+    /// Arbitrary-precision Fibonacci at an astronomically large index
+    /// 
+    /// Demonstrates the fast doubling identities
+    /// F(2k) = F(k)·(2F(k+1) − F(k)) and F(2k+1) = F(k)² + F(k+1)²,
+    /// needing only O(log n) big-number multiplications.
+    #[cfg(feature = "bigint")]
+    pub fn fibonacci_big_index(&self, n: u128) -> Result<BigUint, FibonacciError> {
+        self.fast_doubling_big(n, None)
+    }
+    
+    /// Arbitrary-precision Fibonacci with a wall-clock time budget
+    /// 
+    /// Returns `FibonacciError::Timeout` if the doubling steps run past `limit`.
+    #[cfg(feature = "bigint")]
+    pub fn fibonacci_big_index_with_timeout(&self, n: u128, limit: Duration) -> Result<BigUint, FibonacciError> {
+        self.fast_doubling_big(n, Some(limit))
+    }
+    
+    /// F(F(n)) with no u128 ceiling on the outer value
+    /// 
+    /// The inner F(n) is exact in u128 up to n = 186, but the outer value
+    /// has roughly 0.21·F(n) digits, so only small n are practical:
+    /// n = 30 already yields about 174,000 digits.
+    #[cfg(feature = "bigint")]
+    pub fn fib_of_fib_big(&self, n: u64) -> Result<BigUint, FibonacciError> {
+        let inner = RandyCannabisFibonacci::nth(n)
+            .ok_or(FibonacciError::overflow(n))?;
+        self.fibonacci_big_index(inner)
+    }
+    
+    /// φ to `decimal_places` digits by long division of F(k+1) by F(k)
+    /// 
+    /// The ratio misses φ by about 1/(√5·F(k)²) and F(k)² gains ~0.42
+    /// decimal digits per index, so k ≈ 2.4·(places + 4) leaves a few
+    /// guard digits. The result is truncated, not rounded.
+    #[cfg(feature = "bigint")]
+    pub fn golden_ratio_digits(&self, decimal_places: usize) -> Result<String, String> {
+        let places = u32::try_from(decimal_places).map_err(|_| format!("Too many decimal places: {}", decimal_places))?;
+        let k = (places as u128 + 4) * 12 / 5 + 2;
+        
+        let denominator = self.fibonacci_big_index(k)?;
+        let numerator = self.fibonacci_big_index(k + 1)?;
+        let digits = (numerator * BigUint::from(10u32).pow(places) / denominator).to_string();
+        
+        // φ < 2, so exactly one digit precedes the decimal point
+        let (whole, fraction) = digits.split_at(1);
+        if fraction.is_empty() {
+            Ok(whole.to_string())
+        } else {
+            Ok(format!("{}.{}", whole, fraction))
+        }
+    }
+    
+    /// Fast doubling over the bits of `n`, most significant first
+    #[cfg(feature = "bigint")]
+    fn fast_doubling_big(&self, n: u128, limit: Option<Duration>) -> Result<BigUint, FibonacciError> {
+        let start_time = Instant::now();
+        let mut a = BigUint::from(0u32); // F(k)
+        let mut b = BigUint::from(1u32); // F(k+1)
+        
+        for bit in (0..128 - n.leading_zeros()).rev() {
+            if let Some(limit) = limit {
+                if start_time.elapsed() >= limit {
+                    return Err(FibonacciError::Timeout(limit));
+                }
+            }
+            
+            let doubled = &a * ((&b << 1usize) - &a);
+            let doubled_next = &a * &a + &b * &b;
+            
+            if (n >> bit) & 1 == 0 {
+                a = doubled;
+                b = doubled_next;
+            } else {
+                b = &doubled + &doubled_next;
+                a = doubled_next;
+            }
+        }
+        
+        Ok(a)
+    }
+    
+    /// Pure memoized recursion (true Fibonacci, no strain enhancement)
+    /// 
+    /// Demonstrates top-down dynamic programming with a call-local memo,
+    /// the baseline for the algorithm comparison report.
+    pub fn recursive_fibonacci(&self, n: u64) -> Result<u128, String> {
+        // Reject up front: descending from a huge n would exhaust the stack
+        if n > RandyCannabisFibonacci::MAX_U128_INDEX {
+            return Err(FibonacciError::overflow(n).into());
+        }
+        
+        fn recurse(n: u64, memo: &mut HashMap<u64, u128>) -> Option<u128> {
+            if n <= 1 {
+                return Some(n as u128);
+            }
+            if let Some(&value) = memo.get(&n) {
+                return Some(value);
+            }
+            
+            let value = recurse(n - 1, memo)?.checked_add(recurse(n - 2, memo)?)?;
+            memo.insert(n, value);
+            Some(value)
+        }
+        
+        recurse(n, &mut HashMap::new()).ok_or_else(|| FibonacciError::overflow(n).into())
+    }
+    
+    /// Memoized recursion that also counts its calls
+    /// 
+    /// Returns `(F(n), calls)` using a fresh call-local memo, so the count
+    /// is always 2n − 1 for n >= 1: the memoized "after" to set against
+    /// `naive_call_count`'s exponential "before".
+    pub fn fibonacci_traced(&self, n: u64) -> Result<(u128, u64), String> {
+        if n > RandyCannabisFibonacci::MAX_U128_INDEX {
+            return Err(FibonacciError::overflow(n).into());
+        }
+        
+        fn recurse(n: u64, memo: &mut HashMap<u64, u128>, calls: &mut u64) -> u128 {
+            *calls += 1;
+            if n <= 1 {
+                return n as u128;
+            }
+            if let Some(&value) = memo.get(&n) {
+                return value;
+            }
+            
+            let value = recurse(n - 1, memo, calls) + recurse(n - 2, memo, calls);
+            memo.insert(n, value);
+            value
+        }
+        
+        let mut calls = 0;
+        let value = recurse(n, &mut HashMap::new(), &mut calls);
+        Ok((value, calls))
+    }
+    
+    /// Bottom-up iterative Fibonacci with two rolling variables
+    /// 
+    /// Demonstrates O(n) time and O(1) space with checked addition.
+    pub fn iterative_fibonacci(&self, n: u64) -> Result<u128, String> {
+        if n == 0 {
+            return Ok(0);
+        }
+        
+        let (mut previous, mut current) = (0u128, 1u128);
+        for _ in 1..n {
+            let next = previous.checked_add(current).ok_or(FibonacciError::overflow(n))?;
+            previous = current;
+            current = next;
+        }
+        
+        Ok(current)
+    }
+    
+    /// Accumulator-passing Fibonacci, the functional form of the iterative loop
+    /// 
+    /// Naive recursion F(n) = F(n-1) + F(n-2) leaves an addition pending
+    /// after each call. Threading the pair (F(k), F(k+1)) through as
+    /// arguments makes the recursive call the very last thing done, i.e.
+    /// tail recursion, which is exactly `iterative_fibonacci` in disguise.
+    /// Rust does not guarantee tail-call elimination, so n is capped at
+    /// the u128 boundary up front, keeping the depth at most 186.
+    pub fn fibonacci_accumulator(&self, n: u64) -> Result<u128, String> {
+        fn step(remaining: u64, current: u128, next: u128) -> Option<u128> {
+            match remaining {
+                0 => Some(current),
+                // F(186) is the last value; its successor is never needed
+                1 => Some(next),
+                _ => step(remaining - 1, next, current.checked_add(next)?),
+            }
+        }
+        
+        if n > RandyCannabisFibonacci::MAX_U128_INDEX {
+            return Err(FibonacciError::overflow(n).into());
+        }
+        
+        step(n, 0, 1).ok_or_else(|| FibonacciError::ArithmeticOverflow.into())
+    }
+    
+    /// F(F(n)), the Fibonacci number at a Fibonacci index
+    /// 
+    /// The composition explodes fast: F(F(12)) = F(144) still fits in u128,
+    /// but F(13) = 233 is already past the boundary. Pure Fibonacci at both
+    /// levels. With the `bigint` feature the outer value is computed by
+    /// `fib_of_fib_big` and narrowed to u128, and oversized results point
+    /// there for the exact value, since no u128 can hold them.
+    pub fn fib_of_fib(&self, n: u64) -> Result<u128, String> {
+        let inner = self.iterative_fibonacci(n)?;
+        if inner > RandyCannabisFibonacci::MAX_U128_INDEX as u128 {
+            let hint = if cfg!(feature = "bigint") { "; use fib_of_fib_big for the exact value" } else { "" };
+            return Err(format!("F(F({})) = F({}) exceeds u128 capacity; max is F({}){}", n, inner, RandyCannabisFibonacci::MAX_U128_INDEX, hint));
+        }
+        
+        #[cfg(feature = "bigint")]
+        {
+            let value = self.fib_of_fib_big(n)?;
+            u128::try_from(value).map_err(|_| FibonacciError::ArithmeticOverflow.into())
+        }
+        #[cfg(not(feature = "bigint"))]
+        self.iterative_fibonacci(inner as u64)
+    }
+    
+    /// F(n) in scientific notation with five significant digits, e.g. "3.5422e20"
+    /// 
+    /// Shows the magnitude of results too long to print in full. Values up
+    /// to F(186) are formatted exactly; past that, log10 F(n) comes from
+    /// Binet as n·log10 φ − log10 √5, whose fractional part gives the
+    /// leading digits. That stays accurate up to n = 10^10, beyond which
+    /// f64 cannot hold enough of the fraction. Pure Fibonacci, whatever
+    /// the strain, to match the bigint results it summarizes.
+    pub fn fibonacci_scientific(&self, n: u64) -> Result<String, String> {
+        const MAX_SCIENTIFIC_INDEX: u64 = 10_000_000_000;
+        
+        if let Ok(value) = self.iterative_fibonacci(n) {
+            return Ok(format!("{:.4e}", value as f64));
+        }
+        if n > MAX_SCIENTIFIC_INDEX {
+            return Err(format!("Scientific notation is limited to n <= {}", MAX_SCIENTIFIC_INDEX));
+        }
+        
+        let phi = (1.0 + 5.0_f64.sqrt()) / 2.0;
+        let log10 = n as f64 * phi.log10() - 5.0_f64.sqrt().log10();
+        let exponent = log10.floor();
+        
+        // Format the mantissa alone so rounding 9.99995 up carries into the exponent
+        let mantissa = format!("{:.4e}", 10f64.powf(log10 - exponent));
+        let (digits, carry) = mantissa.split_once('e').unwrap_or((&mantissa, "0"));
+        let carry: u64 = carry.parse().unwrap_or(0);
+        
+        Ok(format!("{}e{}", digits, exponent as u64 + carry))
+    }
+    
+    /// F(n) as little-endian decimal digits, for any index, with no bigint crate
+    /// 
+    /// Schoolbook addition of digit vectors, carrying by hand: index 0 of
+    /// the result is the ones digit. Each step costs O(digits), so the whole
+    /// run is O(n²) and meant for indices in the thousands, not billions.
+    /// Pure Fibonacci, like the bigint path.
+    pub fn fibonacci_digits(&self, n: u64) -> Vec<u8> {
+        let (mut previous, mut current) = (vec![0u8], vec![1u8]);
+        if n == 0 {
+            return previous;
+        }
+        
+        for _ in 1..n {
+            let mut next = Vec::with_capacity(current.len() + 1);
+            let mut carry = 0;
+            for (i, &digit) in current.iter().enumerate() {
+                let sum = digit + previous.get(i).copied().unwrap_or(0) + carry;
+                next.push(sum % 10);
+                carry = sum / 10;
+            }
+            if carry > 0 {
+                next.push(carry);
+            }
+            previous = std::mem::replace(&mut current, next);
+        }
+        
+        current
+    }
+    
+    /// F(n) with explicit u128 wraparound instead of overflow errors
+    /// 
+    /// Past F(186) this is NOT the mathematical Fibonacci number: it is
+    /// F(n) mod 2^128, exactly what repeated `wrapping_add` would produce,
+    /// as in fixed-width modular hardware. Computed by fast doubling with
+    /// wrapping operations so even n = u64::MAX returns instantly.
+    pub fn wrapping_fibonacci(&self, n: u64) -> u128 {
+        let (mut a, mut b) = (0u128, 1u128); // F(k), F(k+1) mod 2^128
+        
+        for bit in (0..64 - n.leading_zeros()).rev() {
+            let doubled = a.wrapping_mul(b.wrapping_mul(2).wrapping_sub(a));
+            let doubled_next = a.wrapping_mul(a).wrapping_add(b.wrapping_mul(b));
+            
+            if (n >> bit) & 1 == 0 {
+                a = doubled;
+                b = doubled_next;
+            } else {
+                a = doubled_next;
+                b = doubled.wrapping_add(doubled_next);
+            }
+        }
+        
+        a
+    }
+    
+    /// Fast doubling Fibonacci in O(log n) u128 operations
+    /// 
+    /// Uses F(2k) = F(k)·(2F(k+1) − F(k)) and F(2k+1) = F(k)² + F(k+1)²,
+    /// finishing with a single half-step so F(n+1) never has to fit in u128.
+    pub fn fast_doubling_fibonacci(&self, n: u64) -> Result<u128, String> {
+        fn pair(k: u64) -> Option<(u128, u128)> {
+            if k == 0 {
+                return Some((0, 1));
+            }
+            
+            let (a, b) = pair(k / 2)?;
+            let doubled = a.checked_mul(b.checked_mul(2)?.checked_sub(a)?)?;
+            let doubled_next = a.checked_mul(a)?.checked_add(b.checked_mul(b)?)?;
+            
+            if k.is_multiple_of(2) {
+                Some((doubled, doubled_next))
+            } else {
+                Some((doubled_next, doubled.checked_add(doubled_next)?))
+            }
+        }
+        
+        let result = if n == 0 {
+            Some(0)
+        } else {
+            pair(n / 2).and_then(|(a, b)| {
+                if n.is_multiple_of(2) {
+                    a.checked_mul(b.checked_mul(2)?.checked_sub(a)?)
+                } else {
+                    a.checked_mul(a)?.checked_add(b.checked_mul(b)?)
+                }
+            })
+        };
+        
+        result.ok_or_else(|| FibonacciError::overflow(n).into())
+    }
+    
+    /// Matrix exponentiation Fibonacci
+    /// 
+    /// Raises [[1,1],[1,0]] to the (n−1)th power by repeated squaring;
+    /// the top-left entry of the result is F(n).
+    pub fn matrix_fibonacci(&self, n: u64) -> Result<u128, String> {
+        if n == 0 {
+            return Ok(0);
+        }
+        
+        Self::matrix_power(n - 1)
+            .map(|matrix| matrix[0][0])
+            .ok_or_else(|| FibonacciError::overflow(n).into())
+    }
+    
+    /// The matrix [[1,1],[1,0]] raised to the nth power
+    /// 
+    /// Its entries are [[F(n+1), F(n)], [F(n), F(n−1)]], so students can
+    /// check the identity directly. n = 0 gives the identity matrix; errors
+    /// once F(n+1) no longer fits in u128.
+    pub fn fibonacci_matrix(&self, n: u64) -> Result<[[u128; 2]; 2], String> {
+        Self::matrix_power(n).ok_or_else(|| {
+            FibonacciError::overflow(n.saturating_add(1)).into()
+        })
+    }
+    
+    /// Checked 2x2 matrix product
+    fn matrix_multiply(a: &[[u128; 2]; 2], b: &[[u128; 2]; 2]) -> Option<[[u128; 2]; 2]> {
+        let mut product = [[0u128; 2]; 2];
+        for (i, row) in product.iter_mut().enumerate() {
+            for (j, cell) in row.iter_mut().enumerate() {
+                *cell = a[i][0].checked_mul(b[0][j])?.checked_add(a[i][1].checked_mul(b[1][j])?)?;
+            }
+        }
+        Some(product)
+    }
+    
+    /// [[1,1],[1,0]]^exponent, squaring the base only while bits remain
+    fn matrix_power(mut exponent: u64) -> Option<[[u128; 2]; 2]> {
+        let mut result = [[1, 0], [0, 1]];
+        let mut base = [[1, 1], [1, 0]];
+        
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = Self::matrix_multiply(&result, &base)?;
+            }
+            exponent >>= 1;
+            if exponent > 0 {
+                base = Self::matrix_multiply(&base, &base)?;
+            }
+        }
+        
+        Some(result)
+    }
+    
+    /// F(n) by whichever algorithm the caller picks at runtime
+    /// 
+    /// All four agree on every index that fits in u128; they differ only
+    /// in speed, which `compare_algorithms` puts side by side.
+    pub fn compute(&self, n: u64, algo: Algorithm) -> Result<u128, String> {
+        match algo {
+            Algorithm::Recursive => self.recursive_fibonacci(n),
+            Algorithm::Iterative => self.iterative_fibonacci(n),
+            Algorithm::FastDoubling => self.fast_doubling_fibonacci(n),
+            Algorithm::Matrix => self.matrix_fibonacci(n),
+        }
+    }
+    
+    /// Compare every Fibonacci algorithm on the same index
+    /// 
+    /// Demonstrates algorithmic complexity side by side: each method's
+    /// value and wall-clock time, with an error if any two disagree.
+    pub fn compare_algorithms(&self, n: u64) -> Result<Vec<(&'static str, u128, Duration)>, String> {
+        let mut report = Vec::with_capacity(Algorithm::ALL.len());
+        for algorithm in Algorithm::ALL {
+            let start_time = Instant::now();
+            let value = self.compute(n, algorithm)?;
+            report.push((algorithm.name(), value, start_time.elapsed()));
+        }
+        
+        if report.iter().any(|&(_, value, _)| value != report[0].1) {
+            return Err(format!("Algorithms disagree on F({})", n));
+        }
+        
+        Ok(report)
+    }
+    
+    /// Analyze the alternate-term ratio F(n+2)/F(n)
+    /// 
+    /// Skipping a term squares the limit: these ratios converge to
+    /// φ² ≈ 2.618 (and their reciprocals to 1/φ²), since φ² = φ + 1.
+    pub fn alternate_ratio_analysis(&self, terms: usize) -> Result<Vec<f64>, String> {
+        let sequence = self.generate_sequence(terms)?;
+        
+        let ratios = sequence
+            .windows(3)
+            .filter(|window| window[0] > 0)
+            .map(|window| window[2] as f64 / window[0] as f64)
+            .collect();
+        
+        Ok(ratios)
+    }
+    
+    /// Side-by-side (pure F(n), enhanced value) pairs for n = 0..count
+    /// 
+    /// Teaching aid showing exactly where the strain multiplier distorts
+    /// the classic sequence. Stops early at the first index where either
+    /// value no longer fits in u128.
+    pub fn compare_enhanced_to_pure(&self, count: usize) -> Vec<(u128, u128)> {
+        (0..count as u64)
+            .map_while(|n| {
+                let pure = RandyCannabisFibonacci::nth(n)?;
+                let enhanced = self.plant_spirit_fibonacci(n).ok()?;
+                Some((pure, enhanced))
+            })
+            .collect()
+    }
+    
+    /// Indices in 0..=up_to where the enhanced value departs from true F(n)
+    /// 
+    /// Migration aid: lists `(n, pure, enhanced)` only where they differ,
+    /// so a Hybrid calculator reports nothing and boosted strains show
+    /// exactly where the multiplier takes over. Indices past either
+    /// overflow boundary are not compared.
+    pub fn audit_strain_correctness(&self, up_to: u64) -> Vec<(u64, u128, u128)> {
+        let count = up_to.saturating_add(1).min(RandyCannabisFibonacci::MAX_U128_INDEX + 1) as usize;
+        
+        self.compare_enhanced_to_pure(count)
+            .into_iter()
+            .enumerate()
+            .filter(|(_, (pure, enhanced))| pure != enhanced)
+            .map(|(n, (pure, enhanced))| (n as u64, pure, enhanced))
+            .collect()
+    }
+    
+    /// Points along the golden spiral built from quarter-circle arcs
+    /// 
+    /// Arc k has radius F(k) for k = 1..=terms and sweeps a quarter turn
+    /// counter-clockwise; each new center steps back by the radius two
+    /// arcs earlier so consecutive arcs join smoothly, as in the classic
+    /// square-tiling picture. Returns the starting point followed by
+    /// `SPIRAL_SEGMENTS_PER_ARC` points per arc, stopping early on overflow.
+    pub fn golden_spiral_points(&self, terms: usize) -> Vec<(f64, f64)> {
+        let segments = RandyCannabisFibonacci::SPIRAL_SEGMENTS_PER_ARC;
+        let quarter_turn = std::f64::consts::FRAC_PI_2;
+        
+        let mut center = (0.0, 0.0);
+        let mut previous_radius = 0.0;
+        let mut points = Vec::new();
+        
+        for k in 1..=terms as u64 {
+            let Ok(value) = self.plant_spirit_fibonacci(k) else { break };
+            let radius = value as f64;
+            let start_angle = (k - 1) as f64 * quarter_turn;
+            
+            // Shift the center so this arc starts where the previous one ended
+            let (dir_x, dir_y) = (start_angle.cos(), start_angle.sin());
+            center = (center.0 + (previous_radius - radius) * dir_x, center.1 + (previous_radius - radius) * dir_y);
+            
+            if points.is_empty() {
+                points.push((center.0 + radius * dir_x, center.1 + radius * dir_y));
+            }
+            for step in 1..=segments {
+                let angle = start_angle + quarter_turn * step as f64 / segments as f64;
+                points.push((center.0 + radius * angle.cos(), center.1 + radius * angle.sin()));
+            }
+            
+            previous_radius = radius;
+        }
+        
+        points
+    }
+    
+    /// Number of memoized entries currently in the cache
+    pub fn cache_len(&self) -> usize {
+        self.cache.len()
+    }
+    
+    /// Sorted list of indices currently memoized in the cache
+    /// 
+    /// Exposes exactly which values are cached, for verifying
+    /// prewarming and eviction behavior.
+    pub fn cached_indices(&self) -> Vec<u64> {
+        let mut indices = self.cache.indices();
+        indices.sort_unstable();
+        indices
+    }
+    
+    /// `(hits, misses)` over every `plant_spirit_fibonacci` lookup so far
+    /// 
+    /// Recursive lookups count too, and clones share the counters along
+    /// with the cache. A `FIB_TABLE` answer is a hit once its prefix has
+    /// been seeded into the cache.
+    pub fn cache_stats(&self) -> (u64, u64) {
+        (self.stats.hits.load(Ordering::Relaxed), self.stats.misses.load(Ordering::Relaxed))
+    }
+    
+    /// Multiply by the strain fraction without intermediate overflow
+    fn apply_strain_ratio(&self, value: u128) -> Option<u128> {
+        scale_by_fraction(value, self.strain_ratio)
+    }
+    
+    /// Name of the strain this calculator was built with
+    pub fn strain_name(&self) -> &str {
+        &self.strain_name
+    }
+    
+    /// F(n) written in any base from 2 to 36
+    /// 
+    /// Digits above 9 use lowercase letters, as `u128::from_str_radix`
+    /// expects, so `fibonacci_in_base(10, 16)` gives "37".
+    pub fn fibonacci_in_base(&self, n: u64, base: u32) -> Result<String, String> {
+        if !(2..=36).contains(&base) {
+            return Err(format!("Base must be within [2, 36], got {}", base));
+        }
+        
+        let mut value = self.plant_spirit_fibonacci(n)?;
+        if value == 0 {
+            return Ok("0".to_string());
+        }
+        
+        let mut digits = Vec::new();
+        while value > 0 {
+            let digit = (value % base as u128) as u32;
+            digits.push(char::from_digit(digit, base).unwrap_or('?'));
+            value /= base as u128;
+        }
+        
+        Ok(digits.into_iter().rev().collect())
+    }
+    
+    /// First `count` terms as a GitHub-flavored Markdown table
+    /// 
+    /// Columns Index, Value and Hex, right-aligned so the digits line up
+    /// when rendered; ready to paste into generated teaching notes.
+    pub fn sequence_to_markdown(&self, count: usize) -> Result<String, String> {
+        let mut table = String::from("| Index | Value | Hex |\n|------:|------:|----:|\n");
+        
+        for (index, value) in self.generate_sequence(count)?.into_iter().enumerate() {
+            table.push_str(&format!("| {} | {} | {:#x} |\n", index, value, value));
+        }
+        
+        Ok(table)
+    }
+    
+    /// Stream the first `count` terms as `index,value` CSV rows to `w`
+    /// 
+    /// Each row is written as soon as its term is computed, so nothing
+    /// larger than one line is ever buffered here. Wrap files or sockets
+    /// in a `BufWriter`. Rows written before an overflow stay written.
+    pub fn write_sequence_csv(&self, count: usize, w: &mut impl std::io::Write) -> Result<(), FibonacciError> {
+        writeln!(w, "index,value")?;
+        
+        for n in 0..count as u64 {
+            writeln!(w, "{},{}", n, self.plant_spirit_fibonacci(n)?)?;
+        }
+        
+        Ok(())
+    }
+    
+    /// Sequence as 16-byte big-endian arrays
+    /// 
+    /// Demonstrates `u128::to_be_bytes` for feeding values into hashing
+    /// or binary protocols in network byte order.
+    pub fn sequence_bytes(&self, count: usize) -> Result<Vec<[u8; 16]>, String> {
+        let sequence = self.generate_sequence(count)?;
+        Ok(sequence.iter().map(|value| value.to_be_bytes()).collect())
+    }
+    
+    /// Find the Fibonacci number closest to an arbitrary value
+    /// 
+    /// Returns `(index, F(index))` for whichever neighbor is nearer.
+    /// Ties go to the lower neighbor, and values above F(186) map to
+    /// F(186), the largest u128 Fibonacci number.
+    pub fn nearest_fibonacci(&self, value: u128) -> (u64, u128) {
+        let (mut lower, mut upper) = (0u128, 1u128);
+        let mut index = 1u64;
+        
+        while upper < value {
+            match lower.checked_add(upper) {
+                Some(next) => {
+                    lower = upper;
+                    upper = next;
+                    index += 1;
+                }
+                None => return (index, upper),
+            }
+        }
+        
+        if upper - value < value - lower {
+            (index, upper)
+        } else {
+            (index - 1, lower)
+        }
+    }
+    
+    /// Fibonacci's rabbits, but mortal
+    /// 
+    /// Each pair matures after one month, then produces one new pair every
+    /// month until it dies at `lifespan` months old. Tracks population by
+    /// age; with a lifespan longer than the horizon nobody dies and the
+    /// total is ordinary F(months).
+    pub fn mortal_rabbits(&self, months: u64, lifespan: u64) -> Result<u128, String> {
+        if lifespan == 0 {
+            return Err("Lifespan must be at least one month".to_string());
+        }
+        if months == 0 {
+            return Ok(0);
+        }
+        
+        // Short lifespans never grow (1: the first pair just dies; 2: each pair
+        // exactly replaces itself), so answer directly rather than loop forever
+        match lifespan {
+            1 => return Ok((months == 1) as u128),
+            2 => return Ok(1),
+            _ => {}
+        }
+        
+        // No pair can be older than the horizon, so cap the age buckets there.
+        // Nobody dies before F(187) overflows, so more buckets are never needed.
+        let buckets = lifespan.min(months).min(RandyCannabisFibonacci::MAX_U128_INDEX + 2) as usize;
+        let mut ages = vec![0u128; buckets];
+        ages[0] = 1;
+        
+        for _ in 1..months {
+            let newborns = ages[1..]
+                .iter()
+                .try_fold(0u128, |total, &pairs| total.checked_add(pairs))
+                .ok_or(FibonacciError::ArithmeticOverflow)?;
+            
+            // Everyone ages a month; the oldest bucket dies and is reused for newborns
+            ages.rotate_right(1);
+            ages[0] = newborns;
+        }
+        
+        let population = ages
+            .iter()
+            .try_fold(0u128, |total, &pairs| total.checked_add(pairs))
+            .ok_or(FibonacciError::ArithmeticOverflow)?;
+        
+        Ok(population)
+    }
+    
+    /// Parity of each term: `true` where F(n) is even
+    /// 
+    /// Fibonacci parity repeats even, odd, odd (F(n) is even exactly when
+    /// 3 divides n), so this is read off the index without computing any
+    /// values and works for any count.
+    pub fn parity_sequence(&self, count: usize) -> Vec<bool> {
+        (0..count).map(|n| n % 3 == 0).collect()
+    }
+    
+    /// Indices n <= `up_to` whose classic F(n) is a perfect square
+    /// 
+    /// Checked with `u128::isqrt`; by Cohn's theorem the answer is always
+    /// some prefix of 0, 1, 2, 12. The scan stops at F(186), the last
+    /// value that fits in u128.
+    pub fn square_fibonacci_indices(&self, up_to: u64) -> Vec<u64> {
+        (0..=up_to.min(RandyCannabisFibonacci::MAX_U128_INDEX))
+            .filter(|&n| {
+                RandyCannabisFibonacci::nth(n).is_some_and(|value| {
+                    let root = value.isqrt();
+                    root * root == value
+                })
+            })
+            .collect()
+    }
+    
+    /// Sum of the even Fibonacci numbers strictly below `limit` (Project Euler #2)
+    /// 
+    /// Every third term is even, and the even terms obey their own
+    /// recurrence E(k) = 4·E(k-1) + E(k-2) from 0 and 2, so the odd terms
+    /// are never generated. Pure Fibonacci; the sum saturates at u128::MAX.
+    pub fn even_fibonacci_sum_below(&self, limit: u128) -> u128 {
+        let (mut previous, mut current) = (0u128, 2u128);
+        let mut sum: u128 = 0;
+        
+        while current < limit {
+            sum = sum.saturating_add(current);
+            let Some(next) = current.checked_mul(4).and_then(|scaled| scaled.checked_add(previous)) else { break };
+            previous = current;
+            current = next;
+        }
+        
+        sum
+    }
+    
+    /// Last decimal digit of F(n) for any n
+    /// 
+    /// Last digits repeat with period 60 (the Pisano period for 10), so
+    /// only F(n mod 60) mod 10 is ever computed.
+    pub fn last_digit(&self, n: u64) -> u8 {
+        let (mut previous, mut current) = (0u8, 1u8);
+        for _ in 0..n % 60 {
+            let next = (previous + current) % 10;
+            previous = current;
+            current = next;
+        }
+        previous
+    }
+    
+    /// Number of trailing zero bits in F(n)
+    /// 
+    /// Shows `u128::trailing_zeros`: F(12) = 144 = 0b1001_0000 gives 4.
+    /// F(0) = 0 has all 128 bits clear. Errors past the overflow boundary.
+    pub fn trailing_zeros(&self, n: u64) -> Result<u32, String> {
+        Ok(self.plant_spirit_fibonacci(n)?.trailing_zeros())
+    }
+    
+    /// Last `digits` decimal digits of F(n) for any n
+    /// 
+    /// Generalizes `last_digit`: F(n) mod 10^digits via `fibonacci_mod`,
+    /// so n can be astronomically large. The last two digits repeat with
+    /// period 300. Leading zeros are dropped, as with any u64. At most 19
+    /// digits fit in u64.
+    pub fn last_n_digits(&self, n: u64, digits: u32) -> Result<u64, String> {
+        let modulus = 10u64
+            .checked_pow(digits)
+            .ok_or_else(|| format!("At most 19 trailing digits fit in u64, got {}", digits))?;
+        self.fibonacci_mod(n, modulus)
+    }
+    
+    /// Sum of squares F(0)² + ... + F(n)² via the closed form F(n)·F(n+1)
+    /// 
+    /// Geometrically, the squares tile an F(n) × F(n+1) rectangle.
+    /// Errors if either factor or the product exceeds u128.
+    pub fn fibonacci_square_sum(&self, n: u64) -> Result<u128, String> {
+        let current = self.iterative_fibonacci(n)?;
+        let next = self.iterative_fibonacci(n + 1)?;
+        
+        current
+            .checked_mul(next)
+            .ok_or_else(|| FibonacciError::ArithmeticOverflow.into())
+    }
+    
+    /// The nth Fibonacci word over {0, 1}
+    /// 
+    /// Starts from "0" at order 1 and applies the morphism 0 → 01, 1 → 0
+    /// once per order, so the word at order n has length F(n+1).
+    pub fn fibonacci_word(&self, n: u64) -> Result<String, String> {
+        if n == 0 {
+            return Err("Fibonacci word order must be at least 1".to_string());
+        }
+        if n > RandyCannabisFibonacci::MAX_WORD_ORDER {
+            return Err(format!("Fibonacci word order is limited to {}", RandyCannabisFibonacci::MAX_WORD_ORDER));
+        }
+        
+        let mut word = String::from("0");
+        for _ in 1..n {
+            word = word
+                .chars()
+                .map(|symbol| if symbol == '0' { "01" } else { "0" })
+                .collect();
+        }
+        
+        Ok(word)
+    }
+    
+    /// Fibonacci coding of a positive integer
+    /// 
+    /// Writes the Zeckendorf representation (no two consecutive Fibonacci
+    /// numbers) least significant first, bit i standing for F(i+2), then
+    /// appends a terminating 1 so every codeword ends in "11".
+    pub fn fibonacci_encode(&self, value: u128) -> Result<String, String> {
+        if value == 0 {
+            return Err("Fibonacci coding is defined for positive integers only".to_string());
+        }
+        
+        // Greedy Zeckendorf: take the largest Fibonacci number that still fits
+        let mut bits = Vec::new();
+        let mut remaining = value;
+        let mut index = (2..=RandyCannabisFibonacci::MAX_U128_INDEX)
+            .rev()
+            .find(|&n| RandyCannabisFibonacci::nth(n).is_some_and(|term| term <= value))
+            .unwrap_or(2);
+        
+        bits.resize(index as usize - 1, '0');
+        while remaining > 0 {
+            let term = RandyCannabisFibonacci::nth(index).unwrap_or(u128::MAX);
+            if term <= remaining {
+                remaining -= term;
+                bits[index as usize - 2] = '1';
+            }
+            index -= 1;
+        }
+        
+        bits.push('1');
+        Ok(bits.into_iter().collect())
+    }
+    
+    /// Optimal move in Fibonacci Nim, or `None` from a losing position
+    /// 
+    /// Players alternate taking tokens; the opening move may take any
+    /// amount short of the whole pile (pass `last_take` 0), and every later
+    /// move at most twice the previous take. Whoever takes the last token
+    /// wins. The winning move is the smallest term of the pile's Zeckendorf
+    /// representation, read off `fibonacci_encode`; when that exceeds the
+    /// allowance, every move loses. An opening pile that is itself a
+    /// Fibonacci number is therefore lost.
+    pub fn fibonacci_nim_winning_move(&self, tokens: u64, last_take: u64) -> Option<u64> {
+        // Empty piles have no Zeckendorf form (and no move to make)
+        let codeword = self.fibonacci_encode(tokens as u128).ok()?;
+        let lowest_bit = codeword.find('1')? as u64;
+        let take = RandyCannabisFibonacci::nth(lowest_bit + 2)? as u64;
+        
+        let allowance = if last_take == 0 { tokens - 1 } else { last_take.saturating_mul(2) };
+        (take <= allowance).then_some(take)
+    }
+    
+    /// Decode a single Fibonacci codeword back to its integer
+    /// 
+    /// Rejects anything that is not 0/1, lacks the terminating "11",
+    /// or contains "11" before the end (not a valid Zeckendorf form).
+    pub fn fibonacci_decode(&self, bits: &str) -> Result<u128, String> {
+        if !bits.chars().all(|bit| bit == '0' || bit == '1') {
+            return Err("Fibonacci codeword may only contain '0' and '1'".to_string());
+        }
+        if !bits.ends_with("11") || bits[..bits.len() - 1].contains("11") {
+            return Err("Fibonacci codeword must end with its only \"11\"".to_string());
+        }
+        
+        let payload = &bits[..bits.len() - 1];
+        payload
+            .chars()
+            .enumerate()
+            .filter(|&(_, bit)| bit == '1')
+            .try_fold(0u128, |total, (position, _)| {
+                RandyCannabisFibonacci::nth(position as u64 + 2).and_then(|term| total.checked_add(term))
+            })
+            .ok_or_else(|| "Fibonacci codeword exceeds u128 capacity".to_string())
+    }
+    
+    /// Export values into a caller-provided FFI buffer
+    /// 
+    /// Writes F(0)..F(count-1) as consecutive 16-byte integers in the
+    /// requested byte order and returns the number of bytes written.
+    /// Nothing is written if `out` is too small.
+    pub fn export_to_buffer(&self, count: usize, little_endian: bool, out: &mut [u8]) -> Result<usize, FibonacciError> {
+        let needed = count.checked_mul(16).ok_or(FibonacciError::ArithmeticOverflow)?;
+        if out.len() < needed {
+            return Err(FibonacciError::BufferTooSmall { needed, available: out.len() });
+        }
+        
+        let sequence = (0..count as u64)
+            .map(|n| self.plant_spirit_fibonacci(n))
+            .collect::<Result<Vec<u128>, FibonacciError>>()?;
+        for (slot, value) in out.chunks_exact_mut(16).zip(&sequence) {
+            let bytes = if little_endian { value.to_le_bytes() } else { value.to_be_bytes() };
+            slot.copy_from_slice(&bytes);
+        }
+        
+        Ok(needed)
+    }
+    
+    /// Highest index reachable with a fixed number of additions
+    /// 
+    /// Starting cold from the seeds F(0) and F(1), each addition of the
+    /// iterative method produces exactly one new term, so `budget`
+    /// additions reach F(budget + 1) - capped at the u128 boundary.
+    pub fn fibonacci_within_budget(&self, budget: usize) -> (u64, u128) {
+        let (mut previous, mut current) = (0u128, 1u128);
+        let mut index = 1u64;
+        
+        for _ in 0..budget {
+            match previous.checked_add(current) {
+                Some(next) => {
+                    previous = current;
+                    current = next;
+                    index += 1;
+                }
+                None => break,
+            }
+        }
+        
+        (index, current)
+    }
+    
+    /// Infallible lazy sequence of plain `u128` values
+    /// 
+    /// Yields F(0)..F(count-1) so standard adapters like `.sum::<u128>()`
+    /// and `.max()` work directly. Terms past the overflow boundary
+    /// saturate to `u128::MAX` instead of erroring.
+    pub fn sequence_iter_saturating(&self, count: usize) -> impl Iterator<Item = u128> + '_ {
+        (0..count as u64).map(move |n| self.plant_spirit_fibonacci(n).unwrap_or(u128::MAX))
+    }
+    
+    /// Lazy `Result`-yielding sequence that stops for good after an error
+    /// 
+    /// Unlike `sequence_iter_saturating`, overflow is reported exactly once
+    /// as `Err` instead of being hidden behind `u128::MAX`.
+    pub fn try_sequence_iter(&self) -> TrySequenceIter<'_, B> {
+        TrySequenceIter {
+            calculator: self,
+            index: 0,
+            finished: false,
+        }
+    }
+    
+    /// F(n) mod m for arbitrarily large n
+    /// 
+    /// Fast doubling with every intermediate reduced mod m, so values
+    /// never grow past m² and no overflow check is needed.
+    pub fn fibonacci_mod(&self, n: u64, m: u64) -> Result<u64, String> {
+        if m == 0 {
+            return Err("Modulus must be at least 1".to_string());
+        }
+        
+        let m = m as u128;
+        let (mut a, mut b) = (0u128, 1 % m); // F(k) mod m, F(k+1) mod m
+        
+        for bit in (0..64 - n.leading_zeros()).rev() {
+            let doubled = a * ((2 * b + m - a) % m) % m;
+            let doubled_next = (a * a % m + b * b % m) % m;
+            
+            if (n >> bit) & 1 == 0 {
+                a = doubled;
+                b = doubled_next;
+            } else {
+                a = doubled_next;
+                b = (doubled + doubled_next) % m;
+            }
+        }
+        
+        Ok(a as u64)
+    }
+    
+    /// F(n) modulo each of several moduli
+    /// 
+    /// One modular pass per modulus; with pairwise-coprime moduli the
+    /// residues reconstruct F(n) via the Chinese Remainder Theorem.
+    pub fn fibonacci_mod_multi(&self, n: u64, moduli: &[u64]) -> Result<Vec<u64>, String> {
+        moduli.iter().map(|&m| self.fibonacci_mod(n, m)).collect()
+    }
+    
+    /// F(0) mod m, ..., F(count-1) mod m, reduced at every step
+    /// 
+    /// Only residues are ever stored, so `count` can go far past the u128
+    /// boundary and the Pisano period shows up as a visibly repeating run.
+    /// A modulus of 0 has no residues and yields an empty sequence.
+    pub fn modular_sequence(&self, count: usize, m: u64) -> Vec<u64> {
+        self.modular_iter(m)
+            .map(|residues| residues.take(count).collect())
+            .unwrap_or_default()
+    }
+    
+    /// Endless lazy F(0) mod m, F(1) mod m, ...
+    /// 
+    /// Allocation-free companion to `modular_sequence` for counts too large
+    /// to collect; stop it with `take` or `take_while`. Unlike the `Vec`
+    /// form, a modulus of 0 is reported as `ZeroModulus`.
+    pub fn modular_iter(&self, m: u64) -> Result<impl Iterator<Item = u64>, FibonacciError> {
+        if m == 0 {
+            return Err(FibonacciError::ZeroModulus);
+        }
+        
+        let (mut previous, mut current) = (0u64, 1 % m);
+        Ok(std::iter::from_fn(move || {
+            let residue = previous;
+            // Sum in u128 so (m-1) + (m-1) cannot overflow for m near u64::MAX
+            let next = ((previous as u128 + current as u128) % m as u128) as u64;
+            previous = current;
+            current = next;
+            Some(residue)
+        }))
+    }
+    
+    /// Longest run of consecutive zeros in F(n) mod m over one Pisano period
+    /// 
+    /// Walks residues from F(0) until the (0, 1) pair recurs, giving up
+    /// after `search_limit` terms. Two adjacent zeros would force every
+    /// later residue to zero, so for m ≥ 2 the answer is always 1, which
+    /// is the point of the exercise: the zeros are evenly spaced singletons.
+    pub fn longest_zero_run_mod(&self, m: u64, search_limit: u64) -> Result<usize, String> {
+        if m == 0 {
+            return Err("Modulus must be at least 1".to_string());
+        }
+        
+        let start = (0u64, 1 % m);
+        let (mut previous, mut current) = start;
+        let (mut longest, mut run) = (0, 0);
+        
+        for _ in 0..search_limit {
+            if previous == 0 {
+                run += 1;
+                longest = longest.max(run);
+            } else {
+                run = 0;
+            }
+            
+            let next = ((previous as u128 + current as u128) % m as u128) as u64;
+            previous = current;
+            current = next;
+            
+            if (previous, current) == start {
+                return Ok(longest);
+            }
+        }
+        
+        Err(format!("No Pisano period for modulus {} within {} terms", m, search_limit))
+    }
+    
+    /// Entry point (rank of apparition) of k: the least n > 0 with k | F(n)
+    /// 
+    /// Steps through F(n) mod k, so any k works regardless of overflow;
+    /// the entry point of 7 is 8 because F(8) = 21. It never exceeds the
+    /// Pisano period, itself at most 6k, so the walk is O(k).
+    pub fn entry_point(&self, k: u64) -> Result<u64, String> {
+        if k == 0 {
+            return Err("Divisor must be at least 1".to_string());
+        }
+        
+        let (mut previous, mut current) = (0u64, 1 % k); // F(n-1), F(n) mod k
+        for n in 1..=k.saturating_mul(6) {
+            if current == 0 {
+                return Ok(n);
+            }
+            let next = ((previous as u128 + current as u128) % k as u128) as u64;
+            previous = current;
+            current = next;
+        }
+        
+        Err(format!("No entry point found for {} within 6·{} terms", k, k))
+    }
+    
+    /// Whether F(m) and F(n) are coprime, decided from the indices alone
+    /// 
+    /// Uses gcd(F(m), F(n)) = F(gcd(m, n)): the values are coprime exactly
+    /// when gcd(m, n) is 1 or 2, since only F(1) = F(2) = 1. Neither value
+    /// is computed, so any indices work. The identity belongs to the
+    /// classic sequence, so strain-enhanced or reseeded calculators error.
+    pub fn are_coprime(&self, m: u64, n: u64) -> Result<bool, String> {
+        if !self.classic_sequence {
+            return Err(format!("Coprimality shortcut holds only for the classic sequence, not {}", self.strain_name));
+        }
+        
+        let (mut a, mut b) = (m, n);
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+        
+        Ok(matches!(a, 1 | 2))
+    }
+    
+    /// Histogram of leading decimal digits (Benford's law demo)
+    /// 
+    /// Index 0 counts leading 1s, index 8 leading 9s. F(0) = 0 has no
+    /// leading digit and is skipped. Fibonacci numbers follow Benford's
+    /// law, so roughly 30% start with 1.
+    pub fn leading_digit_histogram(&self, count: usize) -> Result<[usize; 9], String> {
+        let mut histogram = [0usize; 9];
+        
+        for mut value in self.generate_sequence(count)? {
+            if value == 0 {
+                continue;
+            }
+            while value >= 10 {
+                value /= 10;
+            }
+            histogram[value as usize - 1] += 1;
+        }
+        
+        Ok(histogram)
+    }
+    
+    /// First `count` values keyed by their decimal digit length
+    /// 
+    /// After the opening run of single digits, each length holds four or
+    /// five terms since values grow by about φ per step. Terms past the
+    /// u128 boundary are left out.
+    pub fn group_by_digit_count(&self, count: usize) -> BTreeMap<usize, Vec<u128>> {
+        let mut groups: BTreeMap<usize, Vec<u128>> = BTreeMap::new();
+        
+        for n in 0..count as u64 {
+            let Ok(value) = self.plant_spirit_fibonacci(n) else { break };
+            let digits = value.checked_ilog10().map_or(1, |log| log as usize + 1);
+            groups.entry(digits).or_default().push(value);
+        }
+        
+        groups
+    }
+    
+    /// Lighthearted "dosage" profile for F(n), tiered by its decimal digits
+    /// 
+    /// Deterministic and presentational only. Values too large for u128
+    /// count as the strongest tier.
+    pub fn effect_profile(&self, n: u64) -> EffectProfile {
+        let digits = match self.plant_spirit_fibonacci(n) {
+            Ok(value) => value.checked_ilog10().map_or(1, |log| log + 1),
+            Err(_) => u32::MAX,
+        };
+        
+        let (tier, description) = match digits {
+            0..=2 => (IntensityTier::Microdose, "Barely perceptible: a gentle sprout of a number"),
+            3..=6 => (IntensityTier::Mellow, "Relaxed growth, leaves starting to unfurl"),
+            7..=12 => (IntensityTier::Elevated, "Noticeably lifted: the spiral is taking shape"),
+            13..=24 => (IntensityTier::Intense, "Heavy canopy: numbers this big demand respect"),
+            _ => (IntensityTier::Transcendent, "Full plant spirit communion at the edge of u128"),
+        };
+        
+        EffectProfile { tier, description }
+    }
+    
+    /// Reference to F(n), computing and caching it on first use
+    /// 
+    /// Non-panicking counterpart of `calculator[n]`: `None` on overflow.
+    /// The value is pinned for the calculator's lifetime, so the
+    /// reference stays valid even if `clear_cache` runs later.
+    pub fn get(&self, n: u64) -> Option<&u128> {
+        let value = self.plant_spirit_fibonacci(n).ok()?;
+        let slot = self.pinned.get(n as usize)?;
+        Some(slot.get_or_init(|| value))
+    }
+    
+    /// Largest index this calculator can compute without overflow
+    /// 
+    /// Strain-aware: 186 for the classic sequence, lower for boosted
+    /// strains (146 for Sativa). Read from the `Overflow` diagnostics.
+    pub fn max_index(&self) -> u64 {
+        match self.plant_spirit_fibonacci(RandyCannabisFibonacci::MAX_U128_INDEX) {
+            Err(FibonacciError::Overflow { max, .. }) => max,
+            _ => RandyCannabisFibonacci::MAX_U128_INDEX,
+        }
+    }
+    
+    /// Value of F(max_index()), the largest computable term for this strain
+    pub fn largest_value(&self) -> u128 {
+        // max_index just memoized this term, so the lookup cannot fail
+        self.plant_spirit_fibonacci(self.max_index()).unwrap_or_default()
+    }
+    
+    /// First Fibonacci number strictly greater than a threshold
+    /// 
+    /// Walks the sequence forward with two variables and returns
+    /// `(index, value)`. Errors when no u128 Fibonacci number exceeds
+    /// the threshold (i.e. threshold >= F(186)).
+    pub fn first_exceeding(&self, threshold: u128) -> Result<(u64, u128), String> {
+        let (mut previous, mut current) = (0u128, 1u128);
+        let mut index = 1u64;
+        
+        while current <= threshold {
+            let next = previous
+                .checked_add(current)
+                .ok_or(FibonacciError::Overflow { requested: index + 1, max: index })?;
+            previous = current;
+            current = next;
+            index += 1;
+        }
+        
+        Ok((index, current))
+    }
+    
+    /// Fibonacci extended to negative indices (negafibonacci)
+    /// 
+    /// Uses F(−n) = (−1)^(n+1)·F(n). Negative-index results are memoized
+    /// in their own `i64 -> i128` cache; non-negative indices go through
+    /// `plant_spirit_fibonacci`. Errors if the value does not fit in i128.
+    pub fn signed_fibonacci(&self, n: i64) -> Result<i128, String> {
+        if n >= 0 {
+            let value = self.plant_spirit_fibonacci(n as u64)?;
+            return i128::try_from(value).map_err(|_| FibonacciError::ArithmeticOverflow.into());
+        }
+        
+        if let Some(&value) = self.negative_cache.lock().unwrap_or_else(PoisonError::into_inner).get(&n) {
+            self.negative_stats.hits.fetch_add(1, Ordering::Relaxed);
+            return Ok(value);
+        }
+        
+        let magnitude = n.unsigned_abs();
+        let positive = i128::try_from(self.plant_spirit_fibonacci(magnitude)?)
+            .map_err(|_| FibonacciError::ArithmeticOverflow)?;
+        let value = if magnitude.is_multiple_of(2) { -positive } else { positive };
+        
+        self.negative_cache.lock().unwrap_or_else(PoisonError::into_inner).insert(n, value);
+        self.negative_stats.misses.fetch_add(1, Ordering::Relaxed);
+        Ok(value)
+    }
+    
+    /// Sorted list of negative indices currently memoized
+    pub fn negative_cached_indices(&self) -> Vec<i64> {
+        let mut indices: Vec<i64> = self.negative_cache.lock().unwrap_or_else(PoisonError::into_inner).keys().copied().collect();
+        indices.sort_unstable();
+        indices
+    }
+    
+    /// Number of memoized negafibonacci entries
+    pub fn negative_cache_len(&self) -> usize {
+        self.negative_cache.lock().unwrap_or_else(PoisonError::into_inner).len()
+    }
+    
+    /// `(hits, misses)` over negative-index `signed_fibonacci` lookups
+    /// 
+    /// Kept apart from `cache_stats`: a hit is a value found in the
+    /// negafibonacci cache, a miss is one computed and stored there. The
+    /// positive lookups a miss makes count toward `cache_stats` only.
+    pub fn negative_cache_stats(&self) -> (u64, u64) {
+        (self.negative_stats.hits.load(Ordering::Relaxed), self.negative_stats.misses.load(Ordering::Relaxed))
+    }
+    
+    /// Drop memoized values from both caches, keeping the seeds
+    /// 
+    /// Also zeroes `negative_cache_stats`, since every negative entry is gone.
+    pub fn clear_cache(&self) {
+        for n in self.cache.indices() {
+            if n > 1 {
+                self.cache.remove(n);
+            }
+        }
+        self.table_seeded_to.store(1, Ordering::Release);
+        self.negative_cache.lock().unwrap_or_else(PoisonError::into_inner).clear();
+        self.negative_stats.take();
+    }
+}
+
+/// `calculator[n]`: the cached-or-computed F(n)
+/// 
+/// # Panics
+/// `Index` cannot return a `Result`, so this panics if F(n) overflows
+/// u128 for this strain. Use `get` for a non-panicking lookup.
+impl<B: CacheBackend + 'static> Index<u64> for RandyCannabisFibonacci<B> {
+    type Output = u128;
+    
+    fn index(&self, n: u64) -> &u128 {
+        match self.get(n) {
+            Some(value) => value,
+            None => panic!("F({}) overflows u128 for the {} strain", n, self.strain_name),
+        }
+    }
+}
+
+/// Integer types the shared Fibonacci recurrence can run over
+/// 
+/// Just enough arithmetic for `fibonacci_generic`: the two seeds and an
+/// addition that reports overflow. Fixed-width types return `None` at
+/// their boundary; arbitrary-precision types never do.
+pub trait FibInt: Sized {
+    /// Additive identity, F(0)
+    fn zero() -> Self;
+    /// Multiplicative identity, F(1)
+    fn one() -> Self;
+    /// `self + other`, or `None` if the sum does not fit
+    fn checked_add(&self, other: &Self) -> Option<Self>;
+}
+
+impl FibInt for u64 {
+    fn zero() -> Self {
+        0
+    }
+    
+    fn one() -> Self {
+        1
+    }
+    
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        u64::checked_add(*self, *other)
+    }
+}
+
+impl FibInt for u128 {
+    fn zero() -> Self {
+        0
+    }
+    
+    fn one() -> Self {
+        1
+    }
+    
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        u128::checked_add(*self, *other)
+    }
+}
+
+#[cfg(feature = "bigint")]
+impl FibInt for BigUint {
+    fn zero() -> Self {
+        BigUint::from(0u32)
+    }
+    
+    fn one() -> Self {
+        BigUint::from(1u32)
+    }
+    
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        Some(self + other)
+    }
+}
+
+/// Type-level strain marker for the type-state calculator
+/// 
+/// Each strain is a zero-sized type carrying its multiplier as an
+/// associated const, so strain selection is checked at compile time
+/// instead of matched at runtime.
+pub trait StrainMarker {
+    /// Runtime strain this marker stands for
+    const STRAIN: CannabisStrain;
+    /// Exact multiplier as (numerator, denominator)
+    const MULTIPLIER: (u128, u128);
+}
+
+/// Zero-sized Sativa marker (multiplier 6/5)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Sativa;
+
+/// Zero-sized Indica marker (multiplier 4/5)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Indica;
+
+/// Zero-sized Hybrid marker (multiplier 1/1)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Hybrid;
+
+impl StrainMarker for Sativa {
+    const STRAIN: CannabisStrain = CannabisStrain::Sativa;
+    const MULTIPLIER: (u128, u128) = (6, 5);
+}
+
+impl StrainMarker for Indica {
+    const STRAIN: CannabisStrain = CannabisStrain::Indica;
+    const MULTIPLIER: (u128, u128) = (4, 5);
+}
+
+impl StrainMarker for Hybrid {
+    const STRAIN: CannabisStrain = CannabisStrain::Hybrid;
+    const MULTIPLIER: (u128, u128) = (1, 1);
+}
+
+/// Type-state Fibonacci calculator
+/// 
+/// Demonstrates type-state programming: `Calculator<Sativa>` and
+/// `Calculator<Indica>` are distinct types, complementing the runtime
+/// `CannabisStrain` enum used by `RandyCannabisFibonacci`.
+#[derive(Debug, Clone)]
+pub struct Calculator<S: StrainMarker> {
+    inner: RandyCannabisFibonacci,
+    strain: std::marker::PhantomData<S>,
+}
+
+impl<S: StrainMarker> Calculator<S> {
+    /// Strain multiplier, available in const contexts
+    pub const MULTIPLIER: (u128, u128) = S::MULTIPLIER;
+    
+    /// Create a calculator whose strain is fixed by the type parameter
+    pub fn new() -> Self {
+        Calculator {
+            inner: RandyCannabisFibonacci::new(S::STRAIN),
+            strain: std::marker::PhantomData,
+        }
+    }
+    
+    /// Strain multiplier as a const fn
+    pub const fn multiplier(&self) -> (u128, u128) {
+        S::MULTIPLIER
+    }
+    
+    /// Memoized, strain-enhanced F(n)
+    pub fn fibonacci(&self, n: u64) -> Result<u128, FibonacciError> {
+        self.inner.plant_spirit_fibonacci(n)
+    }
+}
+
+impl<S: StrainMarker> Default for Calculator<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Randy's Cannabis-Enhanced Fibonacci Iterator
+/// 
+/// Demonstrates Rust's iterator traits and lazy evaluation
+/// with infinite cannabis-enhanced mathematical sequences.
+pub struct CannabisFibonacciIterator {
+    current: u128,
+    next: u128,
+    strain: CannabisStrain,
+    count: usize,
+}
+
+impl CannabisFibonacciIterator {
+    pub fn new(strain: CannabisStrain) -> Self {
+        CannabisFibonacciIterator {
+            current: 0,
+            next: 1,
+            strain,
+            count: 0,
+        }
+    }
+    
+    /// Start the iterator at an arbitrary index
+    /// 
+    /// Seeds `current` and `next` with F(index) and F(index + 1) from the
+    /// strain's calculator, computed once up front, so the tail matches
+    /// `new(strain).skip(index)` exactly. Any start past the iteration
+    /// limit or the strain's overflow boundary yields nothing.
+    pub fn starting_at(strain: CannabisStrain, index: u64) -> Self {
+        let mut iterator = Self::new(strain);
+        
+        // Past the iteration limit the iterator is exhausted anyway
+        iterator.count = index.min(101) as usize;
+        if index > 100 {
+            return iterator;
+        }
+        
+        let calculator = RandyCannabisFibonacci::new(strain);
+        iterator.current = calculator.plant_spirit_fibonacci(index).unwrap_or(u128::MAX);
+        iterator.next = calculator.plant_spirit_fibonacci(index + 1).unwrap_or(u128::MAX);
+        
+        iterator
+    }
+    
+    /// Step the recurrence once, returning the value being left behind
+    /// 
+    /// Applies the strain ratio to the whole sum exactly as
+    /// `plant_spirit_fibonacci` does, saturating at the u128 boundary.
+    fn advance(&mut self) -> u128 {
+        let result = self.current;
+        
+        self.current = self.next;
+        self.next = result
+            .checked_add(self.next)
+            .and_then(|sum| scale_by_fraction(sum, self.strain.multiplier_fraction()))
+            .unwrap_or(u128::MAX);
+        
+        result
+    }
+}
+
+impl Iterator for CannabisFibonacciIterator {
+    type Item = u128;
+    
+    fn next(&mut self) -> Option<Self::Item> {
+        let result = self.advance();
+        self.count += 1;
+        
+        // Prevent overflow by limiting iterations
+        if self.count > 100 || result > u128::MAX / 2 {
+            None
+        } else {
+            Some(result)
+        }
+    }
+}
+
+/// Cache-backed iterator borrowed from a calculator
+/// 
+/// Demonstrates `IntoIterator` for references: `for v in &calculator`
+/// walks F(0), F(1), ... through the shared memoization cache and stops
+/// at the u128 overflow boundary.
+pub struct CachedFibonacciIter<'a, B = MutexCache> {
+    calculator: &'a RandyCannabisFibonacci<B>,
+    index: u64,
+}
+
+impl<'a, B: CacheBackend + 'static> Iterator for CachedFibonacciIter<'a, B> {
+    type Item = u128;
+    
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = self.calculator.plant_spirit_fibonacci(self.index).ok()?;
+        self.index += 1;
+        Some(value)
+    }
+}
+
+impl<'a, B: CacheBackend + 'static> IntoIterator for &'a RandyCannabisFibonacci<B> {
+    type Item = u128;
+    type IntoIter = CachedFibonacciIter<'a, B>;
+    
+    fn into_iter(self) -> Self::IntoIter {
+        CachedFibonacciIter {
+            calculator: self,
+            index: 0,
+        }
+    }
+}
+
+/// Fallible, fused sequence iterator borrowed from a calculator
+/// 
+/// Yields `Ok(F(0))`, `Ok(F(1))`, ... and then a single `Err` at the
+/// overflow boundary. After that error (or any other) it is exhausted
+/// and returns `None` forever, as `FusedIterator` promises.
+pub struct TrySequenceIter<'a, B = MutexCache> {
+    calculator: &'a RandyCannabisFibonacci<B>,
+    index: u64,
+    finished: bool,
+}
+
+impl<'a, B: CacheBackend + 'static> Iterator for TrySequenceIter<'a, B> {
+    type Item = Result<u128, FibonacciError>;
+    
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        
+        let result = self.calculator.plant_spirit_fibonacci(self.index);
+        match result {
+            Ok(_) => self.index += 1,
+            Err(_) => self.finished = true,
+        }
+        Some(result)
+    }
+}
+
+impl<'a, B: CacheBackend + 'static> FusedIterator for TrySequenceIter<'a, B> {}
+
+/// What the command line asked for
+#[cfg(feature = "cli")]
+#[derive(Debug, PartialEq)]
+enum CliCommand {
+    /// No arguments: run the interactive menu
+    Menu,
+    /// `--fib N` (optionally with `--strain NAME`): print F(N) and exit
+    Fibonacci { strain: CannabisStrain, n: u64 },
+}
+
+/// Parse arguments (without the program name) using only `std::env::args`
+/// 
+/// Accepts `--fib N` and an optional `--strain NAME` in either order;
+/// the strain defaults to Hybrid. `--strain` alone is rejected rather
+/// than silently opening the menu.
+#[cfg(feature = "cli")]
+fn parse_cli_args(args: impl IntoIterator<Item = String>) -> Result<CliCommand, String> {
+    let mut args = args.into_iter();
+    let (mut strain, mut n) = (None, None);
+    
+    while let Some(flag) = args.next() {
+        let value = args.next().ok_or_else(|| format!("Missing value for {}", flag))?;
+        match flag.as_str() {
+            "--strain" => strain = Some(value.parse::<CannabisStrain>()?),
+            "--fib" => n = Some(value.parse::<u64>().map_err(|_| format!("Invalid Fibonacci index '{}'", value))?),
+            _ => return Err(format!("Unknown argument '{}' (usage: --strain NAME --fib N)", flag)),
+        }
+    }
+    
+    match (strain, n) {
+        (None, None) => Ok(CliCommand::Menu),
+        (strain, Some(n)) => Ok(CliCommand::Fibonacci { strain: strain.unwrap_or(CannabisStrain::Hybrid), n }),
+        (Some(_), None) => Err("--strain requires --fib N".to_string()),
+    }
+}
+
+/// Randy's Cannabis-Enhanced Educational Demo
+/// 
+/// Interactive demonstration of Rust features with cannabis-enhanced
+/// Fibonacci computation and plant spirit programming philosophy.
+/// `--fib N [--strain NAME]` skips the menu and prints one result.
+/// This is the body of the `randy_cannabis_fibonacci` binary target.
+#[cfg(feature = "cli")]
+pub fn run_cli() -> Result<(), Box<dyn std::error::Error>> {
+    // Report argument and calculation errors as plain messages, not Debug output
+    match parse_cli_args(std::env::args().skip(1)) {
+        Ok(CliCommand::Menu) => {}
+        Ok(CliCommand::Fibonacci { strain, n }) => {
+            match RandyCannabisFibonacci::new(strain).plant_spirit_fibonacci(n) {
+                Ok(result) => println!("Fibonacci({}) = {}", n, result),
+                Err(e) => {
+                    eprintln!("Calculation error: {}", e);
+                    std::process::exit(1);
+                }
+            }
+            return Ok(());
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(2);
+        }
+    }
+    
+    println!("\n🦀 RANDY'S CANNABIS-ENHANCED RUST FIBONACCI 🦀");
+    println!("    SYSTEMS PROGRAMMING WITH PLANT SPIRIT SAFETY");
+    println!("    FEARLESS CONCURRENCY AND MEMORY SAFETY");
+    println!();
+    
+    loop {
+        println!("Randy's Rust Programming Menu:");
+        println!("===============================");
+        println!("1. Cannabis-Enhanced Single Fibonacci");
+        println!("2. Generate Fibonacci Sequence");
+        println!("3. Parallel Fibonacci Computation");
+        println!("4. Golden Ratio Convergence Analysis");
+        println!("5. Cannabis Strain Performance Comparison");
+        println!("6. Rust Educational Wisdom");
+        println!("7. Exit to Terminal");
+        println!();
+        
+        print!("Enter choice (1-7): ");
+        io::stdout().flush()?;
+        
+        let mut input = String::new();
+        io::stdin().read_line(&mut input)?;
+        
+        match input.trim() {
+            "1" => single_fibonacci_demo()?,
+            "2" => sequence_generation_demo()?,
+            "3" => parallel_computation_demo()?,
+            "4" => golden_ratio_demo()?,
+            "5" => strain_comparison_demo()?,
+            "6" => display_rust_wisdom(),
+            "7" => {
+                println!("Disconnecting from Rust compiler...");
+                println!("May the borrow checker guide your memory safety journey! 🦀");
+                break;
+            }
+            _ => println!("Invalid choice - please enter 1-7"),
+        }
+        
+        println!("\nPress Enter to continue...");
+        let mut _dummy = String::new();
+        io::stdin().read_line(&mut _dummy)?;
+    }
+    
+    Ok(())
+}
+
+/// Demonstrate single Fibonacci calculation with error handling
+#[cfg(feature = "cli")]
+fn single_fibonacci_demo() -> Result<(), Box<dyn std::error::Error>> {
+    println!("\n🌿 Cannabis-Enhanced Single Fibonacci Calculation 🌿");
+    
+    print!("Enter Fibonacci position (0-{}): ", RandyCannabisFibonacci::MAX_U128_INDEX);
+    io::stdout().flush()?;
+    
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+    
+    let n: u64 = input.trim().parse()
+        .map_err(|_| "Invalid number format")?;
+    
+    let calculator = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+    
+    match calculator.plant_spirit_fibonacci(n) {
+        Ok(result) => {
+            println!("Fibonacci({}) = {}", n, result);
+            println!("Calculated with {} strain enhancement!", calculator.strain_name);
+            let profile = calculator.effect_profile(n);
+            println!("Effect profile: {:?} - {}", profile.tier, profile.description);
+            println!("Memory-safe computation guaranteed by Rust's borrow checker!");
+        }
+        Err(e) => println!("Calculation error: {}", e),
+    }
+    
+    if n <= 10 {
+        print!("Show memoized recursion tree? (y/N): ");
+        io::stdout().flush()?;
+        
+        let mut answer = String::new();
+        io::stdin().read_line(&mut answer)?;
+        
+        if answer.trim().eq_ignore_ascii_case("y") {
+            println!("\n{}", calculator.fibonacci_explain(n)?);
+        }
+    }
+    
+    Ok(())
+}
+
+/// Demonstrate sequence generation with iterator patterns
+#[cfg(feature = "cli")]
+fn sequence_generation_demo() -> Result<(), Box<dyn std::error::Error>> {
+    println!("\n🦀 Rust Iterator Pattern Fibonacci Sequence 🦀");
+    
+    print!("Enter number of terms (1-30): ");
+    io::stdout().flush()?;
+    
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+    
+    let count: usize = input.trim().parse()
+        .map_err(|_| "Invalid number format")?;
+    
+    if count > 30 {
+        println!("Limiting to 30 terms for display purposes");
+        return Ok(());
+    }
+    
+    println!("\nCannabis-Enhanced Fibonacci Iterator:");
+    let fib_iter = CannabisFibonacciIterator::new(CannabisStrain::Sativa);
+    
+    for (i, value) in fib_iter.take(count).enumerate() {
+        println!("F({:2}) = {:>20}", i, value);
+    }
+    
+    println!("\nGenerated with zero-cost abstractions and iterator patterns!");
+    
+    Ok(())
+}
+
+/// Demonstrate parallel computation with fearless concurrency
+#[cfg(feature = "cli")]
+fn parallel_computation_demo() -> Result<(), Box<dyn std::error::Error>> {
+    println!("\n⚡ Fearless Concurrency Fibonacci Computation ⚡");
+    
+    let calculator = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+    
+    println!("Computing Fibonacci numbers 20-40 in parallel...");
+    let start_time = Instant::now();
+    
+    match calculator.parallel_fibonacci_range_instrumented(20, 41, true) {
+        Ok(report) => {
+            let duration = start_time.elapsed();
+            
+            println!("\nParallel Computation Results:");
+            let mut sorted_results: Vec<_> = report.results.iter().collect();
+            sorted_results.sort_by_key(|&(k, _)| k);
+            
+            for &(n, value) in &sorted_results {
+                println!("F({:2}) = {:>25}", n, value);
+            }
+            
+            println!("\nParallel computation completed in {:?}", duration);
+            if let Some(lock_wait) = report.lock_wait {
+                println!("Time workers spent waiting on the shared cache lock: {:?}", lock_wait);
+            }
+            
+            let threadlocal_start = Instant::now();
+            calculator.parallel_fibonacci_threadlocal(20, 41)?;
+            println!("Thread-local caches (no shared lock) took {:?}", threadlocal_start.elapsed());
+            println!("Thread safety guaranteed by Rust's ownership system!");
+        }
+        Err(e) => println!("Parallel computation error: {}", e),
+    }
+    
+    Ok(())
+}
+
+/// Demonstrate mathematical analysis with precision
+#[cfg(feature = "cli")]
+fn golden_ratio_demo() -> Result<(), Box<dyn std::error::Error>> {
+    println!("\n📐 Golden Ratio Convergence Analysis 📐");
+    
+    let calculator = RandyCannabisFibonacci::new(CannabisStrain::Indica);
+    
+    match calculator.golden_ratio_analysis(20) {
+        Ok(ratios) => {
+            let golden_ratio = (1.0 + 5.0_f64.sqrt()) / 2.0;
+            
+            println!("Theoretical Golden Ratio: {:.12}", golden_ratio);
+            println!("\nCannabis-Enhanced Convergence Analysis:");
+            
+            for (i, ratio) in ratios.iter().enumerate() {
+                let error = (ratio - golden_ratio).abs();
+                println!("F({:2})/F({:2}) = {:.12} (error: {:.2e})", 
+                        i + 2, i + 1, ratio, error);
+            }
+            
+            println!("\nPlant spirit mathematical insight:");
+            println!("Golden ratio governs natural growth patterns!");
+            println!("From cannabis leaf arrangements to spiral galaxies!");
+        }
+        Err(e) => println!("Analysis error: {}", e),
+    }
+    
+    Ok(())
+}
+
+/// Compare performance across different cannabis strains
+#[cfg(feature = "cli")]
+fn strain_comparison_demo() -> Result<(), Box<dyn std::error::Error>> {
+    println!("\n🌿 Cannabis Strain Performance Comparison 🌿");
+    
+    let strains = [
+        CannabisStrain::Sativa,
+        CannabisStrain::Indica,
+        CannabisStrain::Hybrid,
+    ];
+    
+    println!("Benchmarking Fibonacci(30) across all strains...\n");
+    
+    for strain in &strains {
+        let calculator = RandyCannabisFibonacci::new(*strain);
+        let (_, personality, description) = strain.characteristics();
+        
+        let start_time = Instant::now();
+        let result = calculator.plant_spirit_fibonacci(30)?;
+        let duration = start_time.elapsed();
+        
+        println!("{:?} Strain ({}):", strain, personality);
+        println!("  Description: {}", description);
+        println!("  Result: {}", result);
+        println!("  Computation Time: {:?}", duration);
+        println!("  Memory Safety: ✓ Guaranteed by Rust");
+        println!();
+    }
+    
+    println!("All computations completed with zero memory leaks!");
+    println!("Rust's ownership system prevents data races and buffer overflows!");
+    
+    Ok(())
+}
+
+/// Display Rust educational wisdom and cannabis-enhanced insights
+#[cfg(feature = "cli")]
+fn display_rust_wisdom() {
+    println!("\n🦀 Randy's Rust Educational Wisdom 🦀");
+    println!("======================================");
+    println!();
+    println!("Rust Language Features:");
+    println!("• Ownership System (Move, Borrow, Lifetime semantics)");
+    println!("• Zero-Cost Abstractions (Performance without overhead)");
+    println!("• Pattern Matching (Exhaustive, expressive control flow)");
+    println!("• Trait System (Safe, composable polymorphism)");
+    println!("• Fearless Concurrency (Data race prevention at compile time)");
+    println!("• Memory Safety (No null pointers, buffer overflows, or leaks)");
+    println!();
+    println!("Cannabis-Enhanced Systems Programming Insights:");
+    println!("• Rust eliminates entire categories of security vulnerabilities");
+    println!("• Compile-time guarantees prevent runtime crashes");
+    println!("• Performance equals C/C++ with safety guarantees");
+    println!("• Package manager (Cargo) handles dependencies elegantly");
+    println!("• Cross-platform compilation without modification");
+    println!("• Growing ecosystem for web, blockchain, and embedded systems");
+    println!();
+    println!("Plant Spirit Programming Philosophy:");
+    println!("• 'If it compiles, it probably works' - Rust's type checker");
+    println!("• Compiler as pair-programming partner and mentor");
+    println!("• Explicit error handling prevents silent failures");
+    println!("• Immutability by default encourages safe programming");
+    println!("• Community-driven development with RFC process");
+    println!("• Inclusivity and accessibility in language design");
+    println!();
+    println!("Rust Industry Applications:");
+    println!("• Operating Systems (Redox OS, Linux kernel modules)");
+    println!("• Web Browsers (Firefox Servo engine)");
+    println!("• Cryptocurrency (Polkadot, Solana blockchains)");
+    println!("• Game Development (Performance-critical engines)");
+    println!("• Embedded Systems (IoT devices, microcontrollers)");
+    println!("• Network Infrastructure (High-performance servers)");
+    println!();
+    println!("Educational Cannabis Revelation:");
+    println!("Rust represents the synthesis of academic programming language");
+    println!("research with practical systems programming needs. The borrow");
+    println!("checker transforms memory management from runtime guesswork");
+    println!("into compile-time mathematical proof. Plant spirit wisdom");
+    println!("recognizes Rust as evolution in programming consciousness -");
+    println!("safety and performance unified through type theory innovation!");
+    println!();
+    println!("🦀 Rust Programming Enlightenment Complete! 🦀");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_basic_fibonacci() {
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        assert_eq!(calc.plant_spirit_fibonacci(0).unwrap(), 0);
+        assert_eq!(calc.plant_spirit_fibonacci(1).unwrap(), 1);
+        assert_eq!(calc.plant_spirit_fibonacci(2).unwrap(), 1);
+        assert_eq!(calc.plant_spirit_fibonacci(3).unwrap(), 2);
+    }
+    
+    #[test]
+    fn test_sequence_generation() {
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Sativa);
+        let sequence = calc.generate_sequence(5).unwrap();
+        assert_eq!(sequence.len(), 5);
+    }
+    
+    #[test]
+    fn test_iterator_pattern() {
+        let iter = CannabisFibonacciIterator::new(CannabisStrain::Indica);
+        let first_five: Vec<_> = iter.take(5).collect();
+        assert_eq!(first_five.len(), 5);
+    }
+    
+    #[test]
+    fn test_overflow_protection() {
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        assert!(calc.plant_spirit_fibonacci(200).is_err());
+    }
+    
+    #[test]
+    fn test_explain_marks_cache_hits() {
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        let trace = calc.fibonacci_explain(5).unwrap();
+        assert!(trace.starts_with("F(5) = F(4) + F(3)"));
+        assert!(trace.contains("F(3) = 2 [cache hit]"));
+        assert!(trace.contains("F(2) = 1 [cache hit]"));
+        assert_eq!(trace.matches("[cache hit]").count(), 2);
+        assert!(trace.ends_with("F(5) = 5 [memoized]\n"));
+        assert!(calc.fibonacci_explain(11).is_err());
+    }
+    
+    #[test]
+    fn test_for_loop_over_calculator() {
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        let mut values = Vec::new();
+        for value in &calc {
+            if values.len() == 10 {
+                break;
+            }
+            values.push(value);
+        }
+        assert_eq!(values, vec![0, 1, 1, 2, 3, 5, 8, 13, 21, 34]);
+        assert_eq!((&calc).into_iter().count(), 187);
+    }
+    
+    #[cfg(feature = "bigint")]
+    #[test]
+    fn test_big_index_fast_doubling() {
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        assert_eq!(calc.fibonacci_big_index(50).unwrap().to_string(),
+                   calc.plant_spirit_fibonacci(50).unwrap().to_string());
+        assert_eq!(calc.fibonacci_big_index(100).unwrap().to_string(), "354224848179261915075");
+        
+        let big = calc.fibonacci_big_index(10_000).unwrap();
+        assert_eq!(big.to_string().len(), 2090);
+        
+        let timed_out = calc.fibonacci_big_index_with_timeout(u128::MAX, Duration::ZERO);
+        assert!(matches!(timed_out, Err(FibonacciError::Timeout(_))));
+    }
+    
+    #[test]
+    fn test_compare_algorithms_agree() {
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        let report = calc.compare_algorithms(100).unwrap();
+        assert_eq!(report.len(), 4);
+        for (_, value, _) in &report {
+            assert_eq!(*value, 354224848179261915075);
+        }
+        
+        let boundary = calc.compare_algorithms(186).unwrap();
+        assert!(boundary.iter().all(|&(_, value, _)| value == boundary[0].1));
+        assert!(calc.compare_algorithms(187).is_err());
+    }
+    
+    #[test]
+    fn test_fixed_point_strains_are_reproducible() {
+        let sativa = RandyCannabisFibonacci::new(CannabisStrain::Sativa);
+        let first: Vec<u128> = sativa.generate_sequence(8).unwrap();
+        assert_eq!(first, vec![0, 1, 1, 2, 3, 6, 10, 19]);
+        
+        let again = RandyCannabisFibonacci::new(CannabisStrain::Sativa);
+        assert_eq!(again.generate_sequence(120).unwrap(), sativa.generate_sequence(120).unwrap());
+        
+        let hybrid = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        assert_eq!(hybrid.plant_spirit_fibonacci(100).unwrap(), 354224848179261915075);
+        assert_eq!(CannabisStrain::Indica.multiplier_fraction(), (4, 5));
+    }
+    
+    #[test]
+    fn test_alternate_ratio_converges_to_phi_squared() {
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        let ratios = calc.alternate_ratio_analysis(40).unwrap();
+        let phi_squared = ((1.0 + 5.0_f64.sqrt()) / 2.0).powi(2);
+        
+        assert_eq!(ratios.len(), 37);
+        assert_eq!(ratios[0], 2.0);
+        assert!((ratios.last().unwrap() - phi_squared).abs() < 1e-12);
+        assert!((ratios[10] - phi_squared).abs() < (ratios[2] - phi_squared).abs());
+    }
+    
+    #[test]
+    fn test_cached_indices_sorted() {
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        assert_eq!(calc.cached_indices(), vec![0, 1]);
+        
+        calc.plant_spirit_fibonacci(7).unwrap();
+        calc.plant_spirit_fibonacci(3).unwrap();
+        calc.plant_spirit_fibonacci(12).unwrap();
+        
+        assert_eq!(calc.cached_indices(), (0..=12).collect::<Vec<u64>>());
+        assert_eq!(calc.cache_len(), 13);
+    }
+    
+    #[test]
+    fn test_enhanced_overflow_reports_error() {
+        let sativa = RandyCannabisFibonacci::new(CannabisStrain::Sativa);
+        let last_valid = sativa.plant_spirit_fibonacci(146).unwrap();
+        assert!(last_valid > u128::MAX / 2);
+        
+        let overflow = sativa.plant_spirit_fibonacci(147);
+        assert_eq!(overflow, Err(FibonacciError::Overflow { requested: 147, max: 146 }));
+    }
+    
+    #[test]
+    fn test_sequence_bytes_big_endian() {
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        let bytes = calc.sequence_bytes(13).unwrap();
+        
+        let mut one = [0u8; 16];
+        one[15] = 1;
+        assert_eq!(bytes[1], one);
+        assert_eq!(bytes[0], [0u8; 16]);
+        assert_eq!(u128::from_be_bytes(bytes[12]), 144);
+    }
+    
+    #[test]
+    fn test_iterator_starting_at() {
+        let tail: Vec<_> = CannabisFibonacciIterator::starting_at(CannabisStrain::Hybrid, 10).take(4).collect();
+        assert_eq!(tail, vec![55, 89, 144, 233]);
+        
+        let skipped: Vec<_> = CannabisFibonacciIterator::new(CannabisStrain::Sativa).skip(20).collect();
+        let started: Vec<_> = CannabisFibonacciIterator::starting_at(CannabisStrain::Sativa, 20).collect();
+        assert_eq!(started, skipped);
+        
+        // Same recurrence as the calculator that seeds it
+        let sativa = RandyCannabisFibonacci::new(CannabisStrain::Sativa);
+        for (n, value) in CannabisFibonacciIterator::new(CannabisStrain::Sativa).enumerate() {
+            assert_eq!(value, sativa.plant_spirit_fibonacci(n as u64).unwrap(), "F({})", n);
+        }
+        
+        assert_eq!(CannabisFibonacciIterator::starting_at(CannabisStrain::Hybrid, 500).next(), None);
+    }
+    
+    #[test]
+    fn test_max_u128_index_boundary() {
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        let max = RandyCannabisFibonacci::MAX_U128_INDEX;
+        assert_eq!(max, 186);
+        assert!(calc.plant_spirit_fibonacci(max).is_ok());
+        assert!(calc.plant_spirit_fibonacci(max + 1).is_err());
+    }
+    
+    #[test]
+    fn test_strain_from_seed_is_deterministic() {
+        for seed in [0, 1, 2, 42, u64::MAX] {
+            assert_eq!(CannabisStrain::from_seed(seed), CannabisStrain::from_seed(seed));
+        }
+        assert_eq!(CannabisStrain::from_seed(3), CannabisStrain::Sativa);
+        assert_eq!(CannabisStrain::from_seed(4), CannabisStrain::Indica);
+        assert_eq!(CannabisStrain::from_seed(5), CannabisStrain::Hybrid);
+    }
+    
+    #[test]
+    fn test_nearest_fibonacci() {
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        assert_eq!(calc.nearest_fibonacci(100), (11, 89));
+        assert_eq!(calc.nearest_fibonacci(120), (12, 144));
+        assert_eq!(calc.nearest_fibonacci(144), (12, 144));
+        assert_eq!(calc.nearest_fibonacci(0), (0, 0));
+        // 4 is equidistant from 3 and 5: ties go to the lower value
+        assert_eq!(calc.nearest_fibonacci(4), (4, 3));
+        assert_eq!(calc.nearest_fibonacci(u128::MAX).0, RandyCannabisFibonacci::MAX_U128_INDEX);
+    }
+    
+    #[test]
+    fn test_with_seeds_accepts_ascending_pair() {
+        let calc = RandyCannabisFibonacci::with_seeds(CannabisStrain::Hybrid, 1, 3).unwrap();
+        assert_eq!(calc.generate_sequence(6).unwrap(), vec![1, 3, 4, 7, 11, 18]);
+    }
+    
+    #[test]
+    fn test_with_seeds_rejects_invalid_pairs() {
+        assert_eq!(
+            RandyCannabisFibonacci::with_seeds(CannabisStrain::Hybrid, 5, 3).unwrap_err(),
+            FibonacciError::InvalidSeeds { first: 5, second: 3 }
+        );
+        assert!(RandyCannabisFibonacci::with_seeds(CannabisStrain::Hybrid, 0, 0).is_err());
+        assert!(RandyCannabisFibonacci::with_seeds(CannabisStrain::Hybrid, u128::MAX, u128::MAX).is_err());
+    }
+    
+    #[test]
+    fn test_mortal_rabbits_long_lifespan_is_fibonacci() {
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        for months in 0..=120 {
+            assert_eq!(calc.mortal_rabbits(months, 1000).unwrap(), calc.plant_spirit_fibonacci(months).unwrap());
+        }
+    }
+    
+    #[test]
+    fn test_mortal_rabbits_short_lifespan() {
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        let populations: Vec<u128> = (1..=6).map(|months| calc.mortal_rabbits(months, 3).unwrap()).collect();
+        assert_eq!(populations, vec![1, 1, 2, 2, 3, 4]);
+        assert!(calc.mortal_rabbits(5, 0).is_err());
+    }
+    
+    #[test]
+    fn test_parallel_cancellation() {
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        
+        let cancel = Arc::new(AtomicBool::new(true));
+        let (partial, status) = calc.parallel_fibonacci_range_cancellable(0, 100, cancel).unwrap();
+        assert_eq!(status, ParallelStatus::Cancelled);
+        assert!(partial.len() < 100);
+        
+        let cancel = Arc::new(AtomicBool::new(false));
+        let (full, status) = calc.parallel_fibonacci_range_cancellable(0, 100, cancel).unwrap();
+        assert_eq!(status, ParallelStatus::Completed);
+        assert_eq!(full.len(), 100);
+    }
+    
+    #[test]
+    fn test_parity_sequence_period_three() {
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        let parity = calc.parity_sequence(12);
+        let pattern = [true, false, false];
+        for (n, &even) in parity.iter().enumerate() {
+            assert_eq!(even, pattern[n % 3]);
+            assert_eq!(even, calc.plant_spirit_fibonacci(n as u64).unwrap().is_multiple_of(2));
+        }
+        assert_eq!(parity.len(), 12);
+    }
+    
+    #[test]
+    fn test_last_digit_period_sixty() {
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        assert_eq!(calc.last_digit(75), calc.last_digit(15));
+        assert_eq!(calc.last_digit(15), (calc.plant_spirit_fibonacci(15).unwrap() % 10) as u8);
+        assert_eq!(calc.last_digit(75), (calc.plant_spirit_fibonacci(75).unwrap() % 10) as u8);
+        for n in 0..=186 {
+            assert_eq!(calc.last_digit(n), (calc.plant_spirit_fibonacci(n).unwrap() % 10) as u8);
+        }
+        assert_eq!(calc.last_digit(u64::MAX), calc.last_digit(u64::MAX % 60));
+    }
+    
+    #[test]
+    fn test_strain_blend() {
+        let even_blend = CannabisStrain::blend(CannabisStrain::Sativa, CannabisStrain::Indica, 0.5).unwrap();
+        assert!((even_blend - 1.0).abs() < 1e-12);
+        assert_eq!(CannabisStrain::blend(CannabisStrain::Sativa, CannabisStrain::Indica, 0.0).unwrap(), 1.2);
+        assert!(CannabisStrain::blend(CannabisStrain::Sativa, CannabisStrain::Indica, 1.5).is_err());
+        
+        let blended = RandyCannabisFibonacci::with_multiplier(even_blend).unwrap();
+        assert_eq!(blended.plant_spirit_fibonacci(50).unwrap(), 12586269025);
+        assert_eq!(blended.strain_name(), "Custom(1.000)");
+        assert!(RandyCannabisFibonacci::with_multiplier(f64::NAN).is_err());
+    }
+    
+    #[test]
+    fn test_square_sum_identity() {
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        assert_eq!(calc.fibonacci_square_sum(5).unwrap(), 40);
+        
+        for n in 0..=90 {
+            let brute: u128 = (0..=n).map(|i| calc.iterative_fibonacci(i).unwrap().pow(2)).sum();
+            assert_eq!(calc.fibonacci_square_sum(n).unwrap(), brute);
+        }
+        assert!(calc.fibonacci_square_sum(100).is_err());
+    }
+    
+    #[test]
+    fn test_export_to_buffer_little_endian() {
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        let mut buffer = [0xffu8; 64];
+        assert_eq!(calc.export_to_buffer(4, true, &mut buffer).unwrap(), 64);
+        
+        let values: Vec<u128> = buffer
+            .chunks_exact(16)
+            .map(|chunk| u128::from_le_bytes(chunk.try_into().unwrap()))
+            .collect();
+        assert_eq!(values, vec![0, 1, 1, 2]);
+        assert_eq!(buffer[16], 1);
+    }
+    
+    #[test]
+    fn test_export_to_buffer_big_endian() {
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        let mut buffer = vec![0u8; 48];
+        assert_eq!(calc.export_to_buffer(3, false, &mut buffer).unwrap(), 48);
+        assert_eq!(buffer[31], 1);
+        assert_eq!(u128::from_be_bytes(buffer[32..48].try_into().unwrap()), 1);
+        
+        let mut small = [0u8; 20];
+        assert_eq!(
+            calc.export_to_buffer(2, false, &mut small),
+            Err(FibonacciError::BufferTooSmall { needed: 32, available: 20 })
+        );
+    }
+    
+    #[test]
+    fn test_const_lookup_table() {
+        assert_eq!(FIB_TABLE[0], 0);
+        assert_eq!(FIB_TABLE[10], 55);
+        assert_eq!(FIB_TABLE[20], 6765);
+        
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        for (n, &value) in FIB_TABLE.iter().enumerate() {
+            assert_eq!(calc.plant_spirit_fibonacci(n as u64).unwrap(), value);
+            assert_eq!(calc.iterative_fibonacci(n as u64).unwrap(), value);
+        }
+        // Served from the table, which seeded the same entries the recursion would have
+        assert_eq!(calc.cached_indices(), (0..=20).collect::<Vec<u64>>());
+        calc.clear_cache();
+        assert_eq!(calc.plant_spirit_fibonacci(5).unwrap(), 5);
+        assert_eq!(calc.cached_indices(), (0..=5).collect::<Vec<u64>>());
+        
+        let seeded = RandyCannabisFibonacci::with_seeds(CannabisStrain::Hybrid, 2, 2).unwrap();
+        assert_eq!(seeded.plant_spirit_fibonacci(2).unwrap(), 4);
+    }
+    
+    #[test]
+    fn test_parallel_contention_instrumentation() {
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        
+        let measured = calc.parallel_fibonacci_range_instrumented(21, 120, true).unwrap();
+        assert!(measured.lock_wait.unwrap() >= Duration::ZERO);
+        assert_eq!(measured.results.len(), 99);
+        
+        let unmeasured = calc.parallel_fibonacci_range_instrumented(21, 120, false).unwrap();
+        assert_eq!(unmeasured.lock_wait, None);
+        assert_eq!(unmeasured.results, measured.results);
+        assert!(calc.lock_wait_nanos.is_none());
+        
+        // The timed hooks behave like get/put apart from the bookkeeping
+        let wait_nanos = AtomicU64::new(0);
+        let backend = RwLockCache::default();
+        backend.put_timed(7, 13, &wait_nanos);
+        assert_eq!(backend.get_timed(7, &wait_nanos), Some(13));
+        assert_eq!(MutexCache::default().get_timed(7, &wait_nanos), None);
+    }
+    
+    #[test]
+    fn test_threadlocal_parallel_matches_serial() {
+        for strain in [CannabisStrain::Hybrid, CannabisStrain::Sativa] {
+            let calc = RandyCannabisFibonacci::new(strain);
+            let threadlocal = calc.parallel_fibonacci_threadlocal(0, 160).unwrap();
+            let shared = calc.parallel_fibonacci_range(0, 160).unwrap();
+            assert_eq!(threadlocal, shared);
+            
+            let serial = RandyCannabisFibonacci::new(strain);
+            for (n, value) in &threadlocal {
+                assert_eq!(serial.plant_spirit_fibonacci(*n).unwrap(), *value);
+            }
+        }
+        // Workers never touched the caller's cache
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Sativa);
+        calc.parallel_fibonacci_threadlocal(30, 40).unwrap();
+        assert_eq!(calc.cache_len(), 2);
+    }
+    
+    #[test]
+    fn test_speed_factor_category() {
+        assert_eq!(CannabisStrain::Sativa.speed_factor_category(), SpeedCategory::Faster);
+        assert_eq!(CannabisStrain::Hybrid.speed_factor_category(), SpeedCategory::Baseline);
+        assert_eq!(CannabisStrain::Indica.speed_factor_category(), SpeedCategory::Slower);
+    }
+    
+    #[test]
+    fn test_fibonacci_within_budget() {
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        assert_eq!(calc.fibonacci_within_budget(10), (11, 89));
+        assert_eq!(calc.fibonacci_within_budget(0), (1, 1));
+        
+        let (index, value) = calc.fibonacci_within_budget(1_000);
+        assert_eq!(index, RandyCannabisFibonacci::MAX_U128_INDEX);
+        assert_eq!(value, calc.iterative_fibonacci(index).unwrap());
+    }
+    
+    #[test]
+    fn test_sequence_iter_saturating_sums() {
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        assert_eq!(calc.sequence_iter_saturating(10).sum::<u128>(), 88);
+        assert_eq!(calc.sequence_iter_saturating(190).last(), Some(u128::MAX));
+    }
+    
+    #[test]
+    fn test_planned_thread_count() {
+        assert_eq!(RandyCannabisFibonacci::planned_thread_count(20, 41, 10), 3);
+        assert_eq!(RandyCannabisFibonacci::planned_thread_count(0, 100, 25), 4);
+        assert_eq!(RandyCannabisFibonacci::planned_thread_count(0, 100, 1), 100);
+        assert_eq!(RandyCannabisFibonacci::planned_thread_count(10, 10, 5), 0);
+        assert_eq!(RandyCannabisFibonacci::planned_thread_count(0, 10, 0), 0);
+        
+        // Past the overflow boundary only the chunks up to F(186) get threads
+        assert_eq!(RandyCannabisFibonacci::planned_thread_count(0, 1000, 10), 19);
+        assert_eq!(RandyCannabisFibonacci::planned_thread_count(180, u64::MAX, 4), 2);
+        assert_eq!(RandyCannabisFibonacci::planned_thread_count(187, 1000, 10), 0);
+        
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        assert_eq!(calc.parallel_fibonacci_range_with(20, 41, 7).unwrap(), calc.parallel_fibonacci_range(20, 41).unwrap());
+        assert!(calc.parallel_fibonacci_range_with(0, 10, 0).is_err());
+    }
+    
+    #[test]
+    fn test_fibonacci_mod_multi() {
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        let moduli = [2, 7, 10, 1_000_000_007, u64::MAX];
+        
+        for n in [0, 1, 50, 186, 10_000] {
+            let residues = calc.fibonacci_mod_multi(n, &moduli).unwrap();
+            for (residue, &m) in residues.iter().zip(&moduli) {
+                assert_eq!(*residue, calc.fibonacci_mod(n, m).unwrap());
+            }
+        }
+        
+        let exact = calc.plant_spirit_fibonacci(186).unwrap();
+        for &m in &moduli {
+            assert_eq!(calc.fibonacci_mod(186, m).unwrap() as u128, exact % m as u128);
+        }
+        assert!(calc.fibonacci_mod_multi(5, &[3, 0]).is_err());
+    }
+    
+    #[test]
+    fn test_leading_digit_histogram_benford() {
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        let histogram = calc.leading_digit_histogram(100).unwrap();
+        
+        assert_eq!(histogram.iter().sum::<usize>(), 99);
+        let most_common = (0..9).max_by_key(|&digit| histogram[digit]).unwrap();
+        assert_eq!(most_common + 1, 1);
+        assert!(histogram[0] > histogram[8]);
+    }
+    
+    #[test]
+    fn test_first_exceeding() {
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        assert_eq!(calc.first_exceeding(100).unwrap(), (12, 144));
+        assert_eq!(calc.first_exceeding(144).unwrap(), (13, 233));
+        assert_eq!(calc.first_exceeding(0).unwrap(), (1, 1));
+        
+        let largest = calc.iterative_fibonacci(RandyCannabisFibonacci::MAX_U128_INDEX).unwrap();
+        assert_eq!(calc.first_exceeding(largest - 1).unwrap().0, RandyCannabisFibonacci::MAX_U128_INDEX);
+        assert!(calc.first_exceeding(largest).is_err());
+    }
+    
+    #[test]
+    fn test_negative_indices_cached_separately() {
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Sativa);
+        assert_eq!(calc.negative_cache_len(), 0);
+        
+        let hybrid = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        let values: Vec<i128> = (-6..=6).map(|n| hybrid.signed_fibonacci(n).unwrap()).collect();
+        assert_eq!(values, vec![-8, 5, -3, 2, -1, 1, 0, 1, 1, 2, 3, 5, 8]);
+        assert_eq!(hybrid.negative_cached_indices(), vec![-6, -5, -4, -3, -2, -1]);
+        
+        calc.signed_fibonacci(-30).unwrap();
+        assert_eq!(calc.negative_cached_indices(), vec![-30]);
+        assert_eq!(calc.negative_cache_stats(), (0, 1));
+        let positive = calc.cache_stats();
+        let first = calc.signed_fibonacci(-30).unwrap();
+        assert_eq!(calc.negative_cache_stats(), (1, 1));
+        assert_eq!(calc.cache_stats(), positive);
+        assert_eq!(calc.signed_fibonacci(-30).unwrap(), first);
+        assert!(calc.cache_len() > 2);
+        
+        calc.clear_cache();
+        assert_eq!(calc.negative_cache_len(), 0);
+        assert_eq!(calc.negative_cache_stats(), (0, 0));
+        assert_eq!(calc.cached_indices(), vec![0, 1]);
+        assert!(hybrid.signed_fibonacci(-186).is_err());
+    }
+    
+    #[test]
+    fn test_type_state_calculator() {
+        const SATIVA_MULTIPLIER: (u128, u128) = Calculator::<Sativa>::MULTIPLIER;
+        assert_eq!(SATIVA_MULTIPLIER, (6, 5));
+        assert_eq!(Calculator::<Sativa>::new().multiplier(), SATIVA_MULTIPLIER);
+        assert_eq!(Sativa::MULTIPLIER, CannabisStrain::Sativa.multiplier_fraction());
+        assert_eq!(Indica::MULTIPLIER, CannabisStrain::Indica.multiplier_fraction());
+        assert_eq!(Hybrid::MULTIPLIER, CannabisStrain::Hybrid.multiplier_fraction());
+        
+        let typed = Calculator::<Sativa>::new();
+        let runtime = RandyCannabisFibonacci::new(CannabisStrain::Sativa);
+        assert_eq!(typed.fibonacci(40).unwrap(), runtime.plant_spirit_fibonacci(40).unwrap());
+        assert_eq!(Calculator::<Hybrid>::default().fibonacci(30).unwrap(), 832040);
+    }
+    
+    /// Test double that records every backend call
+    #[derive(Default)]
+    struct RecordingBackend {
+        inner: MutexCache,
+        calls: Mutex<Vec<String>>,
+    }
+    
+    impl CacheBackend for RecordingBackend {
+        fn get(&self, n: u64) -> Option<u128> {
+            self.calls.lock().unwrap().push(format!("get {}", n));
+            self.inner.get(n)
+        }
+        
+        fn put(&self, n: u64, value: u128) {
+            self.calls.lock().unwrap().push(format!("put {}", n));
+            self.inner.put(n, value);
+        }
+        
+        fn remove(&self, n: u64) {
+            self.inner.remove(n);
+        }
+        
+        fn indices(&self) -> Vec<u64> {
+            self.inner.indices()
+        }
+    }
+    
+    #[test]
+    fn test_custom_cache_backend() {
+        let calc = RandyCannabisFibonacci::with_backend(CannabisStrain::Sativa, RecordingBackend::default());
+        let seeded: Vec<String> = calc.backend().calls.lock().unwrap().clone();
+        assert_eq!(seeded, vec!["put 0", "put 1"]);
+        
+        let value = calc.plant_spirit_fibonacci(3).unwrap();
+        assert_eq!(value, RandyCannabisFibonacci::new(CannabisStrain::Sativa).plant_spirit_fibonacci(3).unwrap());
+        
+        let calls = calc.backend().calls.lock().unwrap().clone();
+        assert_eq!(calls[2], "get 3");
+        assert!(calls.contains(&"put 2".to_string()));
+        assert_eq!(calls.last().map(String::as_str), Some("put 3"));
+        assert_eq!(calc.cached_indices(), vec![0, 1, 2, 3]);
+        
+        // A second lookup is served from the backend without another put
+        calc.plant_spirit_fibonacci(3).unwrap();
+        assert_eq!(calc.backend().calls.lock().unwrap().last().map(String::as_str), Some("get 3"));
+    }
+    
+    #[test]
+    fn test_nth_matches_classic_across_full_range() {
+        let hybrid = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        for n in 0..=RandyCannabisFibonacci::MAX_U128_INDEX {
+            assert_eq!(RandyCannabisFibonacci::nth(n), Some(hybrid.iterative_fibonacci(n).unwrap()), "n = {}", n);
+        }
+        assert_eq!(RandyCannabisFibonacci::nth(187), None);
+        assert_eq!(RandyCannabisFibonacci::nth(u64::MAX), None);
+    }
+    
+    #[test]
+    fn test_compare_enhanced_to_pure() {
+        let hybrid = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        let pairs = hybrid.compare_enhanced_to_pure(200);
+        assert_eq!(pairs.len(), 187);
+        assert!(pairs.iter().all(|(pure, enhanced)| pure == enhanced));
+        
+        // Sativa's floor rounding hides the boost for a few terms, then it drifts upward
+        let sativa = RandyCannabisFibonacci::new(CannabisStrain::Sativa);
+        let pairs = sativa.compare_enhanced_to_pure(20);
+        assert_eq!(&pairs[..5], &[(0, 0), (1, 1), (1, 1), (2, 2), (3, 3)]);
+        assert!(pairs[5..].iter().all(|(pure, enhanced)| enhanced > pure));
+    }
+    
+    #[test]
+    fn test_try_sequence_iter_is_fused_after_error() {
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        let mut iter = calc.try_sequence_iter();
+        
+        let values: Vec<u128> = iter.by_ref().take(187).map(Result::unwrap).collect();
+        assert_eq!(values[186], RandyCannabisFibonacci::nth(186).unwrap());
+        
+        assert!(iter.next().unwrap().is_err());
+        for _ in 0..5 {
+            assert!(iter.next().is_none());
+        }
+    }
+    
+    #[test]
+    fn test_fibonacci_word() {
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        let word = calc.fibonacci_word(5).unwrap();
+        assert_eq!(word, "01001010");
+        assert_eq!(word.len() as u128, RandyCannabisFibonacci::nth(6).unwrap());
+        
+        for n in 1..=20 {
+            assert_eq!(calc.fibonacci_word(n).unwrap().len() as u128, RandyCannabisFibonacci::nth(n + 1).unwrap());
+        }
+        assert!(calc.fibonacci_word(0).is_err());
+        assert!(calc.fibonacci_word(RandyCannabisFibonacci::MAX_WORD_ORDER + 1).is_err());
+    }
+    
+    #[test]
+    fn test_cumulative_sequence() {
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        assert_eq!(calc.cumulative_sequence(5).unwrap(), vec![0, 1, 2, 4, 7]);
+        
+        let totals = calc.cumulative_sequence(30).unwrap();
+        assert_eq!(totals[29], RandyCannabisFibonacci::nth(31).unwrap() - 1);
+        assert!(calc.cumulative_sequence(0).unwrap().is_empty());
+        assert!(calc.cumulative_sequence(187).is_err());
+    }
+    
+    #[test]
+    fn test_overflow_error_fields() {
+        let hybrid = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        let error = hybrid.plant_spirit_fibonacci(200).unwrap_err();
+        assert_eq!(error, FibonacciError::Overflow { requested: 200, max: 186 });
+        assert_eq!(error, FibonacciError::overflow(200));
+        assert!(error.to_string().contains("F(200)"));
+        assert!(error.to_string().contains("max is F(186)"));
+        
+        // The overflow deep inside the recursion is reported against the caller's index
+        let sativa = RandyCannabisFibonacci::new(CannabisStrain::Sativa);
+        match sativa.plant_spirit_fibonacci(150) {
+            Err(FibonacciError::Overflow { requested, max }) => {
+                assert_eq!(requested, 150);
+                assert_eq!(max, 146);
+            }
+            other => panic!("expected overflow, got {:?}", other),
+        }
+    }
+    
+    #[test]
+    fn test_compute_all_strains() {
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        let results = calc.compute_all_strains(30).unwrap();
+        assert_eq!(results.len(), 3);
+        
+        // Each strain's entry is exactly what its own calculator computes
+        for strain in CannabisStrain::ALL {
+            let serial = RandyCannabisFibonacci::new(strain).plant_spirit_fibonacci(30).unwrap();
+            assert_eq!(results[&strain], serial, "{:?}", strain);
+        }
+        assert_eq!(results[&CannabisStrain::Hybrid], 832040);
+        
+        assert!(calc.compute_all_strains(150).is_err());
+    }
+    
+    #[test]
+    fn test_ratio_iter_converges_to_phi() {
+        let phi = (1.0 + 5f64.sqrt()) / 2.0;
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        
+        let first: Vec<f64> = calc.ratio_iter().take(3).collect();
+        assert_eq!(first, vec![1.0, 2.0, 1.5]);
+        
+        let unconverged = calc.ratio_iter().take_while(|ratio| (ratio - phi).abs() > 1e-9).count();
+        assert!(unconverged > 10 && unconverged < 40);
+        assert!((calc.ratio_iter().nth(60).unwrap() - phi).abs() < 1e-12);
+        
+        let ratios = calc.golden_ratio_analysis(30).unwrap();
+        assert_eq!(calc.ratio_iter().take(ratios.len()).collect::<Vec<_>>(), ratios);
+    }
+    
+    #[test]
+    fn test_max_index_and_largest_value() {
+        let hybrid = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        assert_eq!(hybrid.max_index(), 186);
+        assert_eq!(hybrid.largest_value(), 332825110087067562321196029789634457848);
+        
+        let sativa = RandyCannabisFibonacci::new(CannabisStrain::Sativa);
+        assert_eq!(sativa.max_index(), 146);
+        assert_eq!(sativa.largest_value(), sativa.plant_spirit_fibonacci(146).unwrap());
+    }
+    
+    #[test]
+    fn test_fibonacci_coding_round_trip() {
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        assert_eq!(calc.fibonacci_encode(1).unwrap(), "11");
+        assert_eq!(calc.fibonacci_encode(4).unwrap(), "1011");
+        assert_eq!(calc.fibonacci_encode(11).unwrap(), "001011");
+        
+        for value in [1, 2, 3, 7, 65, 1000, 832040, u64::MAX as u128, u128::MAX] {
+            let code = calc.fibonacci_encode(value).unwrap();
+            assert!(code.ends_with("11") && !code[..code.len() - 1].contains("11"));
+            assert_eq!(calc.fibonacci_decode(&code).unwrap(), value);
+        }
+        
+        assert!(calc.fibonacci_encode(0).is_err());
+        assert!(calc.fibonacci_decode("0110").is_err());
+        assert!(calc.fibonacci_decode("11011").is_err());
+        assert!(calc.fibonacci_decode("1021").is_err());
+    }
+    
+    #[test]
+    fn test_from_cache_validates_recurrence() {
+        let source = RandyCannabisFibonacci::new(CannabisStrain::Sativa);
+        source.generate_sequence(40).unwrap();
+        let snapshot: HashMap<u64, u128> = source
+            .cached_indices()
+            .into_iter()
+            .map(|n| (n, source.plant_spirit_fibonacci(n).unwrap()))
+            .collect();
+        
+        let warm = RandyCannabisFibonacci::from_cache(CannabisStrain::Sativa, snapshot.clone()).unwrap();
+        assert_eq!(warm.cache_len(), 40);
+        assert_eq!(warm.plant_spirit_fibonacci(39).unwrap(), source.plant_spirit_fibonacci(39).unwrap());
+        
+        // The same numbers break the Hybrid recurrence
+        let error = RandyCannabisFibonacci::from_cache(CannabisStrain::Hybrid, snapshot.clone()).unwrap_err();
+        assert!(matches!(error, FibonacciError::InvalidCache { .. }));
+        
+        let mut tampered = snapshot;
+        tampered.insert(20, tampered[&20] + 1);
+        assert_eq!(
+            RandyCannabisFibonacci::from_cache(CannabisStrain::Sativa, tampered).unwrap_err(),
+            FibonacciError::InvalidCache { index: 20 }
+        );
+        
+        let bad_seeds = HashMap::from([(0, 1), (1, 1)]);
+        assert_eq!(
+            RandyCannabisFibonacci::from_cache(CannabisStrain::Hybrid, bad_seeds).unwrap_err(),
+            FibonacciError::InvalidCache { index: 0 }
+        );
+    }
+    
+    #[test]
+    fn test_compute_to_channel_preserves_order() {
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Indica);
+        let (tx, rx) = std::sync::mpsc::channel();
+        let producer = calc.compute_to_channel(50, tx).unwrap();
+        
+        let received: Vec<(u64, u128)> = rx.iter().collect();
+        assert!(producer.join().unwrap().is_ok());
+        
+        let expected = calc.generate_sequence(50).unwrap();
+        assert_eq!(received.len(), 50);
+        for (position, (index, value)) in received.into_iter().enumerate() {
+            assert_eq!(index, position as u64);
+            assert_eq!(value, expected[position]);
+        }
+        
+        // Overflow ends the stream with an error after the last valid term
+        let (tx, rx) = std::sync::mpsc::channel();
+        let producer = RandyCannabisFibonacci::new(CannabisStrain::Hybrid).compute_to_channel(200, tx).unwrap();
+        assert_eq!(rx.iter().count(), 187);
+        assert!(producer.join().unwrap().is_err());
+    }
+    
+    #[test]
+    fn test_strain_specific_cache_seeding() {
+        let indica = RandyCannabisFibonacci::new(CannabisStrain::Indica);
+        let sativa = RandyCannabisFibonacci::new(CannabisStrain::Sativa);
+        let hybrid = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        
+        assert_eq!(indica.cache_len(), 31);
+        assert_eq!(indica.cached_indices(), (0..=30).collect::<Vec<u64>>());
+        assert_eq!(sativa.cache_len(), 2);
+        assert_eq!(hybrid.cache_len(), 2);
+        
+        // Prewarming only changes what is cached up front, never the values
+        let lazy = RandyCannabisFibonacci::with_seeds(CannabisStrain::Indica, 0, 1).unwrap();
+        assert_eq!(lazy.cache_len(), 2);
+        assert_eq!(lazy.generate_sequence(40).unwrap(), indica.generate_sequence(40).unwrap());
+    }
+    
+    #[test]
+    fn test_fibonacci_in_base() {
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        assert_eq!(calc.fibonacci_in_base(10, 16).unwrap(), "37");
+        assert_eq!(calc.fibonacci_in_base(10, 2).unwrap(), "110111");
+        assert_eq!(calc.fibonacci_in_base(0, 7).unwrap(), "0");
+        
+        for base in 2..=36 {
+            let rendered = calc.fibonacci_in_base(186, base).unwrap();
+            assert_eq!(u128::from_str_radix(&rendered, base).unwrap(), calc.largest_value());
+        }
+        
+        assert!(calc.fibonacci_in_base(10, 1).is_err());
+        assert!(calc.fibonacci_in_base(10, 37).is_err());
+    }
+    
+    #[test]
+    fn test_generate_until_inclusive() {
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        assert_eq!(calc.generate_until(|value| value > 50).unwrap(), vec![0, 1, 1, 2, 3, 5, 8, 13, 21, 34, 55]);
+        assert_eq!(calc.generate_until(|_| true).unwrap(), vec![0]);
+        assert!(calc.generate_until(|_| false).is_err());
+    }
+    
+    #[test]
+    fn test_golden_ratio_analysis_precise_keeps_digits() {
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        let ratios = calc.golden_ratio_analysis(40).unwrap();
+        let formatted = calc.golden_ratio_analysis_precise(40, 15).unwrap();
+        assert_eq!(formatted.len(), ratios.len());
+        
+        for (text, ratio) in formatted.iter().zip(&ratios) {
+            assert_eq!(text.split('.').nth(1).map(str::len), Some(15));
+            assert!((text.parse::<f64>().unwrap() - ratio).abs() <= 5e-16);
+        }
+        assert_eq!(formatted[37], "1.618033988749895");
+        assert_eq!(calc.golden_ratio_analysis_precise(5, 2).unwrap(), vec!["1.00", "2.00", "1.50"]);
+        
+        assert_eq!(calc.golden_ratio_analysis_precise(5, 100).unwrap()[0].len(), 102);
+        assert!(calc.golden_ratio_analysis_precise(5, 101).is_err());
+        assert!(calc.golden_ratio_analysis_precise(5, 70_000).is_err());
+    }
+    
+    #[test]
+    fn test_reciprocal_sum_approaches_constant() {
+        let reciprocal_fibonacci_constant = 3.359_885_666_243_177_5;
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        
+        assert_eq!(calc.reciprocal_sum(0).unwrap(), 0.0);
+        assert_eq!(calc.reciprocal_sum(3).unwrap(), 2.5);
+        assert!((calc.reciprocal_sum(100).unwrap() - reciprocal_fibonacci_constant).abs() < 1e-12);
+        assert!(calc.reciprocal_sum(187).is_err());
+    }
+    
+    #[test]
+    fn test_terms_for_sum() {
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        assert_eq!(calc.terms_for_sum(10).unwrap(), 6);
+        assert_eq!(calc.terms_for_sum(12).unwrap(), 6);
+        assert_eq!(calc.terms_for_sum(13).unwrap(), 7);
+        assert_eq!(calc.terms_for_sum(0).unwrap(), 0);
+        assert_eq!(calc.terms_for_sum(1).unwrap(), 2);
+        
+        let totals = calc.cumulative_sequence(100).unwrap();
+        let count = calc.terms_for_sum(1_000_000).unwrap() as usize;
+        assert!(totals[count - 1] >= 1_000_000 && totals[count - 2] < 1_000_000);
+        assert!(calc.terms_for_sum(u128::MAX).is_err());
+    }
+    
+    #[test]
+    fn test_fibonacci_matrix_identity() {
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        let matrix = calc.fibonacci_matrix(5).unwrap();
+        assert_eq!(matrix, [[8, 5], [5, 3]]);
+        assert_eq!(matrix[0][0], calc.iterative_fibonacci(6).unwrap());
+        assert_eq!(calc.fibonacci_matrix(0).unwrap(), [[1, 0], [0, 1]]);
+        
+        let edge = calc.fibonacci_matrix(185).unwrap();
+        assert_eq!(edge[0][0], calc.largest_value());
+        assert!(calc.fibonacci_matrix(186).is_err());
+    }
+    
+    #[test]
+    fn test_effect_profile_tiers() {
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        assert_eq!(calc.effect_profile(0).tier, IntensityTier::Microdose);
+        assert_eq!(calc.effect_profile(11).tier, IntensityTier::Microdose);
+        assert_eq!(calc.effect_profile(12).tier, IntensityTier::Mellow);
+        assert_eq!(calc.effect_profile(186).tier, IntensityTier::Transcendent);
+        assert_eq!(calc.effect_profile(500).tier, IntensityTier::Transcendent);
+        
+        // Deterministic and monotonic in the index
+        assert_eq!(calc.effect_profile(60), calc.effect_profile(60));
+        let tiers: Vec<IntensityTier> = (0..=186).map(|n| calc.effect_profile(n).tier).collect();
+        assert!(tiers.windows(2).all(|pair| pair[0] <= pair[1]));
+    }
+    
+    #[test]
+    fn test_index_and_get() {
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        assert_eq!(calc[10], 55);
+        assert_eq!(calc[186], calc.largest_value());
+        assert_eq!(calc.get(30), Some(&832040));
+        assert_eq!(calc.get(187), None);
+        
+        // References survive a cache clear
+        let pinned = calc.get(100).unwrap();
+        calc.clear_cache();
+        assert_eq!(*pinned, 354224848179261915075);
+        
+        let sativa = RandyCannabisFibonacci::new(CannabisStrain::Sativa);
+        assert_eq!(sativa.get(147), None);
+        assert_eq!(sativa[20], sativa.plant_spirit_fibonacci(20).unwrap());
+    }
+    
+    #[test]
+    #[should_panic(expected = "F(147) overflows u128")]
+    fn test_index_panics_on_overflow() {
+        let sativa = RandyCannabisFibonacci::new(CannabisStrain::Sativa);
+        let _ = sativa[147];
+    }
+    
+    #[test]
+    fn test_modular_sequence_pisano_patterns() {
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        assert_eq!(calc.modular_sequence(10, 2), vec![0, 1, 1, 0, 1, 1, 0, 1, 1, 0]);
+        
+        // Pisano period for 10 is 60, far past where the values themselves overflow
+        let digits = calc.modular_sequence(500, 10);
+        assert_eq!(&digits[..60], &digits[60..120]);
+        assert_eq!(digits[450], calc.last_digit(450) as u64);
+        assert_eq!(digits[150] as u128, calc.iterative_fibonacci(150).unwrap() % 10);
+        
+        assert!(calc.modular_sequence(10, 0).is_empty());
+        assert_eq!(calc.modular_sequence(3, 1), vec![0, 0, 0]);
+        assert_eq!(calc.modular_sequence(5, u64::MAX)[4], 3);
+        
+        // The lazy form never materializes the sequence, so any count works
+        let mut residues = calc.modular_iter(10).unwrap();
+        assert_eq!(residues.nth(1_000_060), residues.nth(59));
+        assert_eq!(calc.modular_iter(10).unwrap().take(500).collect::<Vec<u64>>(), digits);
+        assert!(matches!(calc.modular_iter(0), Err(FibonacciError::ZeroModulus)));
+    }
+    
+    #[test]
+    fn test_recursion_dot_node_count() {
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        let dot = calc.recursion_dot(4).unwrap();
+        assert!(dot.starts_with("digraph fibonacci_4 {"));
+        assert!(dot.trim_end().ends_with('}'));
+        
+        // 2·F(5) − 1 = 9 calls, each with one edge from its parent except the root
+        assert_eq!(dot.matches("[label=").count(), 9);
+        assert_eq!(dot.matches(" -> ").count(), 8);
+        assert_eq!(dot.matches("shape=box").count(), 5);
+        
+        assert_eq!(calc.recursion_dot(10).unwrap().matches("[label=").count(), 177);
+        assert!(calc.recursion_dot(11).is_err());
+    }
+    
+    #[test]
+    fn test_naive_vs_memoized_call_counts() {
+        assert_eq!(RandyCannabisFibonacci::naive_call_count(10), 177);
+        assert_eq!(RandyCannabisFibonacci::naive_call_count(0), 1);
+        assert_eq!(RandyCannabisFibonacci::naive_call_count(1), 1);
+        assert_eq!(RandyCannabisFibonacci::naive_call_count(u64::MAX), u64::MAX);
+        
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        assert_eq!(calc.fibonacci_traced(10).unwrap(), (55, 19));
+        let dot_nodes = calc.recursion_dot(10).unwrap().matches("[label=").count() as u64;
+        assert_eq!(dot_nodes, RandyCannabisFibonacci::naive_call_count(10));
+        
+        let (value, calls) = calc.fibonacci_traced(186).unwrap();
+        assert_eq!(value, calc.largest_value());
+        assert_eq!(calls, 371);
+        assert!(calc.fibonacci_traced(187).is_err());
+    }
+    
+    #[test]
+    fn test_wrapping_fibonacci() {
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        for n in 0..=RandyCannabisFibonacci::MAX_U128_INDEX {
+            assert_eq!(calc.wrapping_fibonacci(n), calc.iterative_fibonacci(n).unwrap());
+        }
+        
+        // Past the boundary it matches a plain wrapping_add loop
+        let (mut previous, mut current) = (0u128, 1u128);
+        for _ in 0..300 {
+            let next = previous.wrapping_add(current);
+            previous = current;
+            current = next;
+        }
+        assert_eq!(calc.wrapping_fibonacci(300), previous);
+        let wrapped = calc.largest_value().wrapping_add(calc.iterative_fibonacci(185).unwrap());
+        assert_eq!(calc.wrapping_fibonacci(187), wrapped);
+        let _ = calc.wrapping_fibonacci(u64::MAX);
+    }
+    
+    #[test]
+    fn test_benchmark_result_aggregation() {
+        let result = BenchmarkResult::from(vec![
+            (1, Duration::from_micros(30)),
+            (6, Duration::from_micros(10)),
+            (11, Duration::from_micros(50)),
+        ]);
+        assert_eq!(result.slowest(), Some((11, Duration::from_micros(50))));
+        assert_eq!(result.fastest(), Some((6, Duration::from_micros(10))));
+        assert_eq!(result.total(), Duration::from_micros(90));
+        assert_eq!(result.average(), Some(Duration::from_micros(30)));
+        
+        let empty = BenchmarkResult::from(Vec::new());
+        assert_eq!(empty.slowest(), None);
+        assert_eq!(empty.fastest(), None);
+        assert_eq!(empty.total(), Duration::ZERO);
+        assert_eq!(empty.average(), None);
+        
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        let measured = calc.benchmark_result(50).unwrap();
+        assert_eq!(measured.samples.len(), 10);
+        assert!(measured.fastest().unwrap().1 <= measured.slowest().unwrap().1);
+    }
+    
+    #[test]
+    fn test_terms_to_converge() {
+        let phi = (1.0 + 5.0_f64.sqrt()) / 2.0;
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        let terms = calc.terms_to_converge(1e-6).unwrap();
+        
+        // The last of those terms over its predecessor is the first ratio within tolerance
+        let sequence = calc.generate_sequence(terms).unwrap();
+        let ratio = |i: usize| sequence[i] as f64 / sequence[i - 1] as f64;
+        assert!((ratio(terms - 1) - phi).abs() < 1e-6);
+        assert!((ratio(terms - 2) - phi).abs() >= 1e-6);
+        assert_eq!(terms, 18);
+        
+        assert!(calc.terms_to_converge(1e-3).unwrap() < terms);
+        assert!(calc.terms_to_converge(1e-20).is_err());
+        assert!(calc.terms_to_converge(f64::NAN).is_err());
+        assert!(RandyCannabisFibonacci::new(CannabisStrain::Sativa).terms_to_converge(1e-6).is_err());
+    }
+    
+    #[test]
+    fn test_compute_indices() {
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        assert_eq!(calc.compute_indices(&[10, 0, 30]).unwrap(), vec![55, 0, 832040]);
+        assert_eq!(
+            calc.compute_indices(&[5, 200]).unwrap_err(),
+            FibonacciError::Overflow { requested: 200, max: 186 }
+        );
+    }
+    
+    #[cfg(feature = "async")]
+    #[test]
+    fn test_compute_many_async() {
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Sativa);
+        let indices = vec![120, 5, 60, 0, 146];
+        
+        let values = runtime.block_on(calc.compute_many_async(indices.clone())).unwrap();
+        let expected = RandyCannabisFibonacci::new(CannabisStrain::Sativa).compute_indices(&indices).unwrap();
+        assert_eq!(values, expected);
+        assert!(calc.cached_indices().contains(&146));
+        
+        assert!(runtime.block_on(calc.compute_many_async(vec![10, 147])).is_err());
+    }
+    
+    #[test]
+    fn test_audit_strain_correctness() {
+        let hybrid = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        assert!(hybrid.audit_strain_correctness(u64::MAX).is_empty());
+        
+        let sativa = RandyCannabisFibonacci::new(CannabisStrain::Sativa);
+        let report = sativa.audit_strain_correctness(20);
+        assert_eq!(report.first(), Some(&(5, 5, 6)));
+        assert_eq!(report.iter().map(|&(n, _, _)| n).collect::<Vec<_>>(), (5..=20).collect::<Vec<_>>());
+        
+        let indica = RandyCannabisFibonacci::new(CannabisStrain::Indica);
+        assert!(indica.audit_strain_correctness(30).iter().all(|&(_, pure, enhanced)| enhanced < pure));
+    }
+    
+    #[test]
+    fn test_golden_spiral_points() {
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        let segments = RandyCannabisFibonacci::SPIRAL_SEGMENTS_PER_ARC;
+        let terms = 10;
+        let points = calc.golden_spiral_points(terms);
+        assert_eq!(points.len(), 1 + terms * segments);
+        
+        // A quarter circle of radius r has a chord of r·√2 between its ends
+        let sequence = calc.generate_sequence(terms + 1).unwrap();
+        for arc in 0..terms {
+            let (start, end) = (points[arc * segments], points[(arc + 1) * segments]);
+            let chord = ((end.0 - start.0).powi(2) + (end.1 - start.1).powi(2)).sqrt();
+            let radius = sequence[arc + 1] as f64;
+            assert!((chord - radius * 2f64.sqrt()).abs() < 1e-9, "arc {} radius mismatch", arc);
+        }
+        
+        assert!(calc.golden_spiral_points(0).is_empty());
+        assert_eq!(calc.golden_spiral_points(500).len(), 1 + 186 * segments);
+    }
+    
+    #[test]
+    fn test_group_by_digit_count() {
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        let groups = calc.group_by_digit_count(20);
+        
+        assert_eq!(groups[&1], vec![0, 1, 1, 2, 3, 5, 8]);
+        assert_eq!(groups[&2], vec![13, 21, 34, 55, 89]);
+        assert_eq!(groups.values().map(Vec::len).sum::<usize>(), 20);
+        
+        // Overflowing terms are dropped rather than failing the whole grouping
+        let all = calc.group_by_digit_count(500);
+        assert_eq!(all.values().map(Vec::len).sum::<usize>(), 187);
+        assert!(calc.group_by_digit_count(0).is_empty());
+    }
+    
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_benchmark_result_serde_round_trip() {
+        let result = BenchmarkResult::from(vec![
+            (1, Duration::from_nanos(1_234)),
+            (6, Duration::from_micros(10)),
+            (11, Duration::new(2, 5)),
+        ]);
+        
+        let json = serde_json::to_string(&result).unwrap();
+        assert!(json.contains("2000000005"), "durations should serialize as nanoseconds: {}", json);
+        
+        let restored: BenchmarkResult = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, result);
+        assert_eq!(restored.slowest(), result.slowest());
+        assert_eq!(restored.fastest(), result.fastest());
+        assert_eq!(restored.total(), result.total());
+        assert_eq!(restored.average(), result.average());
+    }
+    
+    #[test]
+    fn test_trailing_zeros() {
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        assert_eq!(calc.trailing_zeros(12), Ok(4)); // 144 = 0b1001_0000
+        assert_eq!(calc.trailing_zeros(6), Ok(3)); // 8
+        assert_eq!(calc.trailing_zeros(7), Ok(0)); // 13 is odd
+        assert_eq!(calc.trailing_zeros(0), Ok(128));
+        assert!(calc.trailing_zeros(187).is_err());
+    }
+    
+    /// Canonical oracle: the 187 Fibonacci numbers that fit in a u128,
+    /// F(0) through F(186), written out rather than computed
+    mod known_values {
+        use super::*;
+        
+        const FIBONACCI_U128: [u128; 187] = [
+            0, 1, 1, 2, 3, 5, 8, 13, 21, 34, 55, 89, 144, 233, 377, 610, 987, 1597, 2584, 4181, 6765, 10946,
+            17711, 28657, 46368, 75025, 121393, 196418, 317811, 514229, 832040, 1346269, 2178309, 3524578,
+            5702887, 9227465, 14930352, 24157817, 39088169, 63245986, 102334155, 165580141, 267914296,
+            433494437, 701408733, 1134903170, 1836311903, 2971215073, 4807526976, 7778742049, 12586269025,
+            20365011074, 32951280099, 53316291173, 86267571272, 139583862445, 225851433717, 365435296162,
+            591286729879, 956722026041, 1548008755920, 2504730781961, 4052739537881, 6557470319842,
+            10610209857723, 17167680177565, 27777890035288, 44945570212853, 72723460248141, 117669030460994,
+            190392490709135, 308061521170129, 498454011879264, 806515533049393, 1304969544928657,
+            2111485077978050, 3416454622906707, 5527939700884757, 8944394323791464, 14472334024676221,
+            23416728348467685, 37889062373143906, 61305790721611591, 99194853094755497, 160500643816367088,
+            259695496911122585, 420196140727489673, 679891637638612258, 1100087778366101931,
+            1779979416004714189, 2880067194370816120, 4660046610375530309, 7540113804746346429,
+            12200160415121876738, 19740274219868223167, 31940434634990099905, 51680708854858323072,
+            83621143489848422977, 135301852344706746049, 218922995834555169026, 354224848179261915075,
+            573147844013817084101, 927372692193078999176, 1500520536206896083277, 2427893228399975082453,
+            3928413764606871165730, 6356306993006846248183, 10284720757613717413913, 16641027750620563662096,
+            26925748508234281076009, 43566776258854844738105, 70492524767089125814114,
+            114059301025943970552219, 184551825793033096366333, 298611126818977066918552,
+            483162952612010163284885, 781774079430987230203437, 1264937032042997393488322,
+            2046711111473984623691759, 3311648143516982017180081, 5358359254990966640871840,
+            8670007398507948658051921, 14028366653498915298923761, 22698374052006863956975682,
+            36726740705505779255899443, 59425114757512643212875125, 96151855463018422468774568,
+            155576970220531065681649693, 251728825683549488150424261, 407305795904080553832073954,
+            659034621587630041982498215, 1066340417491710595814572169, 1725375039079340637797070384,
+            2791715456571051233611642553, 4517090495650391871408712937, 7308805952221443105020355490,
+            11825896447871834976429068427, 19134702400093278081449423917, 30960598847965113057878492344,
+            50095301248058391139327916261, 81055900096023504197206408605, 131151201344081895336534324866,
+            212207101440105399533740733471, 343358302784187294870275058337, 555565404224292694404015791808,
+            898923707008479989274290850145, 1454489111232772683678306641953, 2353412818241252672952597492098,
+            3807901929474025356630904134051, 6161314747715278029583501626149, 9969216677189303386214405760200,
+            16130531424904581415797907386349, 26099748102093884802012313146549,
+            42230279526998466217810220532898, 68330027629092351019822533679447,
+            110560307156090817237632754212345, 178890334785183168257455287891792,
+            289450641941273985495088042104137, 468340976726457153752543329995929,
+            757791618667731139247631372100066, 1226132595394188293000174702095995,
+            1983924214061919432247806074196061, 3210056809456107725247980776292056,
+            5193981023518027157495786850488117, 8404037832974134882743767626780173,
+            13598018856492162040239554477268290, 22002056689466296922983322104048463,
+            35600075545958458963222876581316753, 57602132235424755886206198685365216,
+            93202207781383214849429075266681969, 150804340016807970735635273952047185,
+            244006547798191185585064349218729154, 394810887814999156320699623170776339,
+            638817435613190341905763972389505493, 1033628323428189498226463595560281832,
+            1672445759041379840132227567949787325, 2706074082469569338358691163510069157,
+            4378519841510949178490918731459856482, 7084593923980518516849609894969925639,
+            11463113765491467695340528626429782121, 18547707689471986212190138521399707760,
+            30010821454963453907530667147829489881, 48558529144435440119720805669229197641,
+            78569350599398894027251472817058687522, 127127879743834334146972278486287885163,
+            205697230343233228174223751303346572685, 332825110087067562321196029789634457848,
+        ];
+        
+        #[test]
+        fn test_hybrid_matches_known_values() {
+            let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+            
+            for (n, &expected) in FIBONACCI_U128.iter().enumerate() {
+                let n = n as u64;
+                assert_eq!(calc.plant_spirit_fibonacci(n), Ok(expected), "plant_spirit_fibonacci({})", n);
+                assert_eq!(calc.iterative_fibonacci(n), Ok(expected), "iterative_fibonacci({})", n);
+                assert_eq!(calc.fast_doubling_fibonacci(n), Ok(expected), "fast_doubling_fibonacci({})", n);
+                assert_eq!(calc.matrix_fibonacci(n), Ok(expected), "matrix_fibonacci({})", n);
+                assert_eq!(RandyCannabisFibonacci::nth(n), Some(expected), "nth({})", n);
+            }
+            
+            // The table ends exactly at the u128 boundary
+            assert_eq!(FIBONACCI_U128.len() as u64, RandyCannabisFibonacci::MAX_U128_INDEX + 1);
+            assert!(calc.plant_spirit_fibonacci(187).is_err());
+        }
+    }
+    
+    #[test]
+    fn test_stride_sequence() {
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        assert_eq!(calc.stride_sequence(2, 5).unwrap(), vec![0, 1, 3, 8, 21]);
+        assert_eq!(calc.stride_sequence(1, 6).unwrap(), calc.generate_sequence(6).unwrap());
+        
+        // Every third term is even
+        let thirds = calc.stride_sequence(3, 20).unwrap();
+        assert!(thirds.iter().all(|value| value % 2 == 0));
+        
+        assert!(calc.stride_sequence(0, 5).is_err());
+        assert!(calc.stride_sequence(100, 3).is_err());
+        assert!(calc.stride_sequence(u64::MAX, 3).is_err());
+        assert_eq!(calc.stride_sequence(7, 0).unwrap(), Vec::<u128>::new());
+    }
+    
+    #[test]
+    fn test_binet_interval_brackets_true_value() {
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        
+        for n in [0, 1, 2, 10, 50, 70, 71, 78, 100, 150, 186] {
+            let expected = calc.iterative_fibonacci(n).unwrap();
+            let (lower, upper) = calc.binet_interval(n);
+            assert!(lower <= upper, "empty interval for F({})", n);
+            // Compare as integers so rounding `expected` to f64 cannot hide a miss
+            assert!(lower.ceil() as u128 <= expected && expected <= upper.floor() as u128,
+                "F({}) = {} outside [{}, {}]", n, expected, lower, upper);
+        }
+        
+        // Small indices are pinned down to a single integer
+        let (lower, upper) = calc.binet_interval(20);
+        assert_eq!((lower.ceil(), upper.floor()), (6765.0, 6765.0));
+        
+        let (lower, upper) = calc.binet_interval(u64::MAX);
+        assert!(lower <= upper && upper.is_infinite());
+    }
+    
+    #[test]
+    fn test_longest_zero_run_mod() {
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        
+        // Mod 3 the period is 0, 1, 1, 2, 0, 2, 2, 1: zeros never touch
+        assert_eq!(calc.longest_zero_run_mod(3, 100), Ok(1));
+        assert_eq!(calc.longest_zero_run_mod(10, 60), Ok(1));
+        assert_eq!(calc.longest_zero_run_mod(1, 10), Ok(1));
+        
+        // The period for 10 is 60 terms, so a shorter search cannot finish
+        assert!(calc.longest_zero_run_mod(10, 59).is_err());
+        assert!(calc.longest_zero_run_mod(0, 100).is_err());
+    }
+    
+    #[test]
+    fn test_fold_sequence() {
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        
+        // 1 · 1 · 2 · 3 · 5 · 8, skipping F(0) so the product is not zero
+        let product = calc.fold_sequence(7, 1u128, |acc, value| if value == 0 { acc } else { acc * value });
+        assert_eq!(product, Ok(240));
+        
+        assert_eq!(calc.fold_sequence(10, 0u128, |acc, value| acc.max(value)), Ok(34));
+        assert_eq!(calc.fold_sequence(10, 0u128, |acc, value| acc + value), Ok(88));
+        assert_eq!(calc.fold_sequence(0, "untouched", |acc, _| acc), Ok("untouched"));
+        assert!(calc.fold_sequence(200, 0u32, |acc, _| acc + 1).is_err());
+    }
+    
+    #[test]
+    fn test_generate_sequence_with_progress() {
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        let mut reported = Vec::new();
+        
+        let sequence = calc.generate_sequence_with_progress(25, |index| reported.push(index)).unwrap();
+        assert_eq!(sequence, calc.generate_sequence(25).unwrap());
+        assert_eq!(reported, (0..25).collect::<Vec<_>>());
+        
+        // Progress stops at the last term that actually fit
+        reported.clear();
+        assert!(calc.generate_sequence_with_progress(200, |index| reported.push(index)).is_err());
+        assert_eq!(reported.len(), 187);
+    }
+    
+    #[test]
+    fn test_strain_from_str() {
+        assert_eq!("sativa".parse::<CannabisStrain>(), Ok(CannabisStrain::Sativa));
+        assert_eq!("INDICA".parse::<CannabisStrain>(), Ok(CannabisStrain::Indica));
+        assert_eq!("Hybrid".parse::<CannabisStrain>(), Ok(CannabisStrain::Hybrid));
+        assert!("ruderalis".parse::<CannabisStrain>().is_err());
+    }
+    
+    #[cfg(feature = "cli")]
+    #[test]
+    fn test_parse_cli_args() {
+        let args = |list: &[&str]| list.iter().map(|arg| arg.to_string()).collect::<Vec<_>>();
+        
+        assert_eq!(parse_cli_args(args(&[])), Ok(CliCommand::Menu));
+        assert_eq!(
+            parse_cli_args(args(&["--strain", "sativa", "--fib", "50"])),
+            Ok(CliCommand::Fibonacci { strain: CannabisStrain::Sativa, n: 50 })
+        );
+        assert_eq!(
+            parse_cli_args(args(&["--fib", "7"])),
+            Ok(CliCommand::Fibonacci { strain: CannabisStrain::Hybrid, n: 7 })
+        );
+        
+        assert!(parse_cli_args(args(&["--strain", "indica"])).is_err());
+        assert!(parse_cli_args(args(&["--fib"])).is_err());
+        assert!(parse_cli_args(args(&["--fib", "-3"])).is_err());
+        assert!(parse_cli_args(args(&["--verbose", "yes"])).is_err());
+    }
+    
+    #[test]
+    fn test_fibonacci_scientific() {
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Sativa);
+        let scientific = calc.fibonacci_scientific(100).unwrap();
+        assert!(scientific.starts_with("3.54") && scientific.ends_with("e20"), "{}", scientific);
+        assert_eq!(scientific, "3.5422e20");
+        assert_eq!(calc.fibonacci_scientific(0).unwrap(), "0.0000e0");
+        
+        // Past u128 the Binet estimate takes over
+        for (n, leading, exponent) in [(187, "5.385", "e38"), (1000, "4.346", "e208"), (10_000, "3.364", "e2089")] {
+            let scientific = calc.fibonacci_scientific(n).unwrap();
+            assert!(scientific.starts_with(leading) && scientific.ends_with(exponent), "F({}) = {}", n, scientific);
+        }
+        
+        assert!(calc.fibonacci_scientific(u64::MAX).is_err());
+    }
+    
+    #[test]
+    fn test_is_valid_fibonacci_sequence() {
+        assert!(RandyCannabisFibonacci::is_valid_fibonacci_sequence(&[0, 1, 1, 2, 3, 5, 8, 13]));
+        assert!(RandyCannabisFibonacci::is_valid_fibonacci_sequence(&[2, 1, 3, 4, 7, 11])); // Lucas
+        assert!(!RandyCannabisFibonacci::is_valid_fibonacci_sequence(&[0, 1, 1, 2, 4, 5, 8]));
+        assert!(!RandyCannabisFibonacci::is_valid_fibonacci_sequence(&[u128::MAX, 1, 0]));
+        
+        assert!(RandyCannabisFibonacci::is_valid_fibonacci_sequence(&[]));
+        assert!(RandyCannabisFibonacci::is_valid_fibonacci_sequence(&[42]));
+        assert!(RandyCannabisFibonacci::is_valid_fibonacci_sequence(&[9, 4]));
+    }
+    
+    #[test]
+    fn test_fill_cache_to() {
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        calc.fill_cache_to(50).unwrap();
+        assert_eq!(calc.cached_indices(), (0..=50).collect::<Vec<u64>>());
+        
+        // Filled values match what the recursive path computes on a fresh calculator
+        let sativa = RandyCannabisFibonacci::new(CannabisStrain::Sativa);
+        sativa.fill_cache_to(146).unwrap();
+        let reference = RandyCannabisFibonacci::new(CannabisStrain::Sativa);
+        assert_eq!(sativa.get(146), Some(&reference.plant_spirit_fibonacci(146).unwrap()));
+        
+        // Sativa's boundary is F(146), so filling past it stops with an error
+        let overflowing = RandyCannabisFibonacci::new(CannabisStrain::Sativa);
+        assert_eq!(overflowing.fill_cache_to(186), Err(FibonacciError::Overflow { requested: 186, max: 146 }));
+        assert!(calc.fill_cache_to(187).is_err());
+    }
+    
+    #[test]
+    fn test_enhanced_growth_ratio() {
+        let phi = (1.0 + 5.0_f64.sqrt()) / 2.0;
+        let sativa = RandyCannabisFibonacci::new(CannabisStrain::Sativa);
+        let indica = RandyCannabisFibonacci::new(CannabisStrain::Indica);
+        let hybrid = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        
+        assert!(sativa.enhanced_growth_ratio(60) > phi);
+        assert!(indica.enhanced_growth_ratio(60) < phi);
+        assert!((hybrid.enhanced_growth_ratio(60) - phi).abs() < 1e-12);
+        
+        // Closed-form limit (m + √(m² + 4m)) / 2 for Sativa's m = 1.2
+        assert!((sativa.enhanced_growth_ratio(60) - (1.2 + 6.24_f64.sqrt()) / 2.0).abs() < 1e-12);
+        
+        // Sativa's exact u128 values grow at the same rate
+        let empirical = sativa.plant_spirit_fibonacci(146).unwrap() as f64 / sativa.plant_spirit_fibonacci(145).unwrap() as f64;
+        assert!((empirical - sativa.enhanced_growth_ratio(146)).abs() < 1e-9);
+        
+        assert!(sativa.enhanced_growth_ratio(2).is_nan());
+        // Far past where the values themselves would overflow f64
+        assert!(sativa.enhanced_growth_ratio(100_000).is_finite());
+    }
+    
+    #[test]
+    fn test_entry_point() {
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        assert_eq!(calc.entry_point(7), Ok(8)); // F(8) = 21
+        assert_eq!(calc.entry_point(1), Ok(1));
+        assert_eq!(calc.entry_point(2), Ok(3));
+        assert_eq!(calc.entry_point(10), Ok(15)); // F(15) = 610
+        
+        // Entry points agree with a brute-force search over actual values
+        for k in 1..60u64 {
+            let expected = (1..).find(|&n| calc.iterative_fibonacci(n).unwrap().is_multiple_of(k as u128)).unwrap();
+            assert_eq!(calc.entry_point(k), Ok(expected), "entry point of {}", k);
+        }
+        
+        assert!(calc.entry_point(0).is_err());
     }
     
-    println!("\nCannabis-Enhanced Fibonacci Iterator:");
-    let fib_iter = CannabisFibonacciIterator::new(CannabisStrain::Sativa);
-    
-    for (i, value) in fib_iter.take(count).enumerate() {
-        println!("F({:2}) = {:>20}", i, value);
+    #[test]
+    fn test_fibonacci_generic() {
+        assert_eq!(RandyCannabisFibonacci::fibonacci_generic::<u64>(0), Some(0));
+        assert_eq!(RandyCannabisFibonacci::fibonacci_generic::<u64>(93), Some(12200160415121876738));
+        assert_eq!(RandyCannabisFibonacci::fibonacci_generic::<u64>(94), None);
+        
+        assert_eq!(RandyCannabisFibonacci::fibonacci_generic::<u128>(100), Some(354224848179261915075));
+        assert_eq!(RandyCannabisFibonacci::fibonacci_generic::<u128>(186), RandyCannabisFibonacci::nth(186));
+        assert_eq!(RandyCannabisFibonacci::fibonacci_generic::<u128>(187), None);
     }
     
-    println!("\nGenerated with zero-cost abstractions and iterator patterns!");
+    #[cfg(feature = "bigint")]
+    #[test]
+    fn test_fibonacci_generic_biguint() {
+        let big = RandyCannabisFibonacci::fibonacci_generic::<BigUint>(100).unwrap();
+        assert_eq!(big.to_string(), "354224848179261915075");
+        
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        let past_u128 = RandyCannabisFibonacci::fibonacci_generic::<BigUint>(500).unwrap();
+        assert_eq!(past_u128, calc.fibonacci_big_index(500).unwrap());
+    }
     
-    Ok(())
-}
-
-/// Demonstrate parallel computation with fearless concurrency
-fn parallel_computation_demo() -> Result<(), Box<dyn std::error::Error>> {
-    println!("\n⚡ Fearless Concurrency Fibonacci Computation ⚡");
+    #[test]
+    fn test_are_coprime() {
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        assert_eq!(calc.are_coprime(10, 11), Ok(true)); // consecutive: 55, 89
+        assert_eq!(calc.are_coprime(6, 8), Ok(true)); // gcd 2: 8, 21
+        assert_eq!(calc.are_coprime(6, 9), Ok(false)); // gcd 3: 8, 34 share 2
+        assert_eq!(calc.are_coprime(12, 8), Ok(false)); // gcd 4: 144, 21 share 3
+        assert_eq!(calc.are_coprime(0, 0), Ok(false));
+        assert_eq!(calc.are_coprime(u64::MAX, u64::MAX - 1), Ok(true));
+        
+        // The shortcut agrees with computing the gcd of the actual values
+        for m in 0..40u64 {
+            for n in 0..40u64 {
+                let (mut a, mut b) = (calc.iterative_fibonacci(m).unwrap(), calc.iterative_fibonacci(n).unwrap());
+                while b != 0 {
+                    (a, b) = (b, a % b);
+                }
+                assert_eq!(calc.are_coprime(m, n), Ok(a == 1), "F({}), F({})", m, n);
+            }
+        }
+        
+        assert!(RandyCannabisFibonacci::new(CannabisStrain::Sativa).are_coprime(3, 4).is_err());
+    }
     
-    let calculator = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+    #[test]
+    fn test_fibonacci_digits() {
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        let to_string = |digits: Vec<u8>| digits.iter().rev().map(|digit| char::from(b'0' + digit)).collect::<String>();
+        
+        let digits = calc.fibonacci_digits(100);
+        assert_eq!(digits.len(), 21);
+        assert_eq!(digits[0], 5); // little-endian: ones digit first
+        assert_eq!(to_string(digits), "354224848179261915075");
+        
+        assert_eq!(calc.fibonacci_digits(0), vec![0]);
+        assert_eq!(calc.fibonacci_digits(1), vec![1]);
+        for n in [2, 12, 93, 186] {
+            assert_eq!(to_string(calc.fibonacci_digits(n)), calc.iterative_fibonacci(n).unwrap().to_string());
+        }
+        
+        // Well past u128: F(1000) has 209 digits
+        assert_eq!(calc.fibonacci_digits(1000).len(), 209);
+    }
     
-    println!("Computing Fibonacci numbers 20-40 in parallel...");
-    let start_time = Instant::now();
+    #[test]
+    fn test_generate_sequence_truncating() {
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        let truncated = calc.generate_sequence_truncating(300);
+        assert_eq!(truncated.len(), 187);
+        assert_eq!(truncated, calc.generate_sequence(187).unwrap());
+        
+        assert_eq!(calc.generate_sequence_truncating(10), calc.generate_sequence(10).unwrap());
+        assert_eq!(RandyCannabisFibonacci::new(CannabisStrain::Sativa).generate_sequence_truncating(300).len(), 147);
+        assert!(calc.generate_sequence_truncating(0).is_empty());
+    }
     
-    match calculator.parallel_fibonacci_range(20, 41) {
-        Ok(results) => {
-            let duration = start_time.elapsed();
-            
-            println!("\nParallel Computation Results:");
-            let mut sorted_results: Vec<_> = results.iter().collect();
-            sorted_results.sort_by_key(|&(k, _)| k);
-            
-            for &(n, value) in &sorted_results {
-                println!("F({:2}) = {:>25}", n, value);
-            }
-            
-            println!("\nParallel computation completed in {:?}", duration);
-            println!("Thread safety guaranteed by Rust's ownership system!");
+    #[test]
+    fn test_strain_safe_max_index() {
+        assert!(CannabisStrain::Sativa.safe_max_index() < CannabisStrain::Hybrid.safe_max_index());
+        
+        // The static table matches what each calculator actually reaches
+        for strain in CannabisStrain::ALL {
+            let calc = RandyCannabisFibonacci::new(strain);
+            assert_eq!(strain.safe_max_index(), calc.max_index(), "{:?}", strain);
+            assert!(calc.plant_spirit_fibonacci(strain.safe_max_index()).is_ok());
         }
-        Err(e) => println!("Parallel computation error: {}", e),
     }
     
-    Ok(())
-}
-
-/// Demonstrate mathematical analysis with precision
-fn golden_ratio_demo() -> Result<(), Box<dyn std::error::Error>> {
-    println!("\n📐 Golden Ratio Convergence Analysis 📐");
+    #[test]
+    fn test_even_fibonacci_sum_below() {
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        assert_eq!(calc.even_fibonacci_sum_below(4_000_000), 4613732);
+        assert_eq!(calc.even_fibonacci_sum_below(34), 10); // 2 + 8, since 34 itself is excluded
+        assert_eq!(calc.even_fibonacci_sum_below(35), 44);
+        assert_eq!(calc.even_fibonacci_sum_below(0), 0);
+        
+        // Matches filtering the sequence for even values
+        let limit = calc.iterative_fibonacci(150).unwrap();
+        let expected: u128 = calc.generate_sequence(150).unwrap().into_iter().filter(|value| value % 2 == 0).sum();
+        assert_eq!(calc.even_fibonacci_sum_below(limit), expected);
+        
+        // The even terms below u128::MAX add up to more than u128 holds
+        assert_eq!(calc.even_fibonacci_sum_below(u128::MAX), u128::MAX);
+    }
     
-    let calculator = RandyCannabisFibonacci::new(CannabisStrain::Indica);
+    #[test]
+    fn test_fibonacci_accumulator() {
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        for n in 0..=RandyCannabisFibonacci::MAX_U128_INDEX {
+            assert_eq!(calc.fibonacci_accumulator(n), calc.iterative_fibonacci(n), "F({})", n);
+        }
+        assert!(calc.fibonacci_accumulator(187).is_err());
+        assert!(calc.fibonacci_accumulator(u64::MAX).is_err());
+    }
     
-    match calculator.golden_ratio_analysis(20) {
-        Ok(ratios) => {
-            let golden_ratio = (1.0 + 5.0_f64.sqrt()) / 2.0;
-            
-            println!("Theoretical Golden Ratio: {:.12}", golden_ratio);
-            println!("\nCannabis-Enhanced Convergence Analysis:");
-            
-            for (i, ratio) in ratios.iter().enumerate() {
-                let error = (ratio - golden_ratio).abs();
-                println!("F({:2})/F({:2}) = {:.12} (error: {:.2e})", 
-                        i + 2, i + 1, ratio, error);
+    #[test]
+    fn test_compute_dispatches_every_algorithm() {
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        for algorithm in Algorithm::ALL {
+            assert_eq!(calc.compute(93, algorithm), Ok(12200160415121876738), "{:?}", algorithm);
+            assert!(calc.compute(187, algorithm).is_err(), "{:?}", algorithm);
+        }
+        
+        let names: Vec<&str> = calc.compare_algorithms(93).unwrap().into_iter().map(|(name, _, _)| name).collect();
+        assert_eq!(names, Algorithm::ALL.map(|algorithm| algorithm.name()));
+    }
+    
+    #[test]
+    fn test_fibonacci_nim_winning_move() {
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        
+        // 20 = 13 + 5 + 2, so take the smallest Zeckendorf term
+        assert_eq!(calc.fibonacci_nim_winning_move(20, 0), Some(2));
+        // A Fibonacci-sized opening pile is lost
+        assert_eq!(calc.fibonacci_nim_winning_move(21, 0), None);
+        // 11 = 8 + 3, but after a take of 1 at most 2 may be taken
+        assert_eq!(calc.fibonacci_nim_winning_move(11, 1), None);
+        assert_eq!(calc.fibonacci_nim_winning_move(11, 2), Some(3));
+        assert_eq!(calc.fibonacci_nim_winning_move(0, 5), None);
+        
+        // Cross-check against exhaustive game search on small piles
+        fn wins(tokens: u64, allowance: u64) -> bool {
+            (1..=allowance.min(tokens)).any(|take| take == tokens || !wins(tokens - take, 2 * take))
+        }
+        for tokens in 2..30 {
+            for last_take in 0..12 {
+                let allowance = if last_take == 0 { tokens - 1 } else { 2 * last_take };
+                match calc.fibonacci_nim_winning_move(tokens, last_take) {
+                    Some(take) => assert!(take == tokens || !wins(tokens - take, 2 * take), "{} after {}", tokens, last_take),
+                    None => assert!(!wins(tokens, allowance), "{} after {} should be winnable", tokens, last_take),
+                }
             }
-            
-            println!("\nPlant spirit mathematical insight:");
-            println!("Golden ratio governs natural growth patterns!");
-            println!("From cannabis leaf arrangements to spiral galaxies!");
         }
-        Err(e) => println!("Analysis error: {}", e),
     }
     
-    Ok(())
-}
-
-/// Compare performance across different cannabis strains
-fn strain_comparison_demo() -> Result<(), Box<dyn std::error::Error>> {
-    println!("\n🌿 Cannabis Strain Performance Comparison 🌿");
+    #[test]
+    fn test_last_n_digits() {
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        assert_eq!(calc.last_n_digits(1000, 2), Ok(75));
+        assert_eq!(calc.last_n_digits(1000, 19), Ok(6137795166849228875));
+        assert_eq!(calc.last_n_digits(100, 3), Ok(75)); // ...915075
+        
+        // Period 300 for the last two digits
+        assert_eq!(calc.last_n_digits(1000 + 300, 2), calc.last_n_digits(1000, 2));
+        assert_eq!(u64::from(calc.last_digit(u64::MAX)), calc.last_n_digits(u64::MAX, 1).unwrap());
+        
+        assert_eq!(calc.last_n_digits(50, 0), Ok(0));
+        assert!(calc.last_n_digits(50, 20).is_err());
+    }
     
-    let strains = [
-        CannabisStrain::Sativa,
-        CannabisStrain::Indica,
-        CannabisStrain::Hybrid,
-    ];
+    #[test]
+    fn test_fib_of_fib() {
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Sativa);
+        assert_eq!(calc.fib_of_fib(5), Ok(5)); // F(5) = 5 is a fixed point
+        assert_eq!(calc.fib_of_fib(0), Ok(0));
+        assert_eq!(calc.fib_of_fib(6), Ok(21)); // F(8)
+        assert_eq!(calc.fib_of_fib(12), calc.iterative_fibonacci(144));
+        assert!(calc.fib_of_fib(13).is_err());
+        assert!(calc.fib_of_fib(u64::MAX).is_err());
+    }
     
-    println!("Benchmarking Fibonacci(30) across all strains...\n");
+    #[cfg(feature = "bigint")]
+    #[test]
+    fn test_fib_of_fib_big() {
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        assert_eq!(calc.fib_of_fib_big(12).unwrap().to_string(), calc.fib_of_fib(12).unwrap().to_string());
+        // F(F(13)) = F(233) has 49 digits
+        assert_eq!(calc.fib_of_fib_big(13).unwrap().to_string().len(), 49);
+        assert!(calc.fib_of_fib_big(187).is_err());
+        
+        // The u128 API shares this path and names it when the value is too big
+        assert_eq!(calc.fib_of_fib(12).unwrap(), RandyCannabisFibonacci::nth(144).unwrap());
+        assert!(calc.fib_of_fib(13).unwrap_err().contains("fib_of_fib_big"));
+    }
     
-    for strain in &strains {
-        let calculator = RandyCannabisFibonacci::new(*strain);
-        let (_, personality, description) = strain.characteristics();
+    #[test]
+    fn test_sequence_to_markdown() {
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        let table = calc.sequence_to_markdown(13).unwrap();
+        let lines: Vec<&str> = table.lines().collect();
         
-        let start_time = Instant::now();
-        let result = calculator.plant_spirit_fibonacci(30)?;
-        let duration = start_time.elapsed();
+        assert_eq!(lines[0], "| Index | Value | Hex |");
+        assert_eq!(lines.len(), 2 + 13);
+        assert_eq!(lines[2], "| 0 | 0 | 0x0 |");
+        assert_eq!(lines[14], "| 12 | 144 | 0x90 |");
         
-        println!("{:?} Strain ({}):", strain, personality);
-        println!("  Description: {}", description);
-        println!("  Result: {}", result);
-        println!("  Computation Time: {:?}", duration);
-        println!("  Memory Safety: ✓ Guaranteed by Rust");
-        println!();
+        assert_eq!(calc.sequence_to_markdown(0).unwrap().lines().count(), 2);
+        assert!(calc.sequence_to_markdown(200).is_err());
     }
     
-    println!("All computations completed with zero memory leaks!");
-    println!("Rust's ownership system prevents data races and buffer overflows!");
+    #[cfg(feature = "bigint")]
+    #[test]
+    fn test_golden_ratio_digits() {
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        let phi = calc.golden_ratio_digits(10).unwrap();
+        assert_eq!(phi, "1.6180339887");
+        assert_eq!(&phi[2..], "6180339887");
+        assert_eq!(calc.golden_ratio_digits(0).unwrap(), "1");
+        
+        // Fifty places, far beyond what f64 can hold
+        let long = calc.golden_ratio_digits(50).unwrap();
+        assert_eq!(long, "1.61803398874989484820458683436563811772030917980576");
+    }
     
-    Ok(())
-}
-
-/// Display Rust educational wisdom and cannabis-enhanced insights
-fn display_rust_wisdom() {
-    println!("\n🦀 Randy's Rust Educational Wisdom 🦀");
-    println!("======================================");
-    println!();
-    println!("Rust Language Features:");
-    println!("• Ownership System (Move, Borrow, Lifetime semantics)");
-    println!("• Zero-Cost Abstractions (Performance without overhead)");
-    println!("• Pattern Matching (Exhaustive, expressive control flow)");
-    println!("• Trait System (Safe, composable polymorphism)");
-    println!("• Fearless Concurrency (Data race prevention at compile time)");
-    println!("• Memory Safety (No null pointers, buffer overflows, or leaks)");
-    println!();
-    println!("Cannabis-Enhanced Systems Programming Insights:");
-    println!("• Rust eliminates entire categories of security vulnerabilities");
-    println!("• Compile-time guarantees prevent runtime crashes");
-    println!("• Performance equals C/C++ with safety guarantees");
-    println!("• Package manager (Cargo) handles dependencies elegantly");
-    println!("• Cross-platform compilation without modification");
-    println!("• Growing ecosystem for web, blockchain, and embedded systems");
-    println!();
-    println!("Plant Spirit Programming Philosophy:");
-    println!("• 'If it compiles, it probably works' - Rust's type checker");
-    println!("• Compiler as pair-programming partner and mentor");
-    println!("• Explicit error handling prevents silent failures");
-    println!("• Immutability by default encourages safe programming");
-    println!("• Community-driven development with RFC process");
-    println!("• Inclusivity and accessibility in language design");
-    println!();
-    println!("Rust Industry Applications:");
-    println!("• Operating Systems (Redox OS, Linux kernel modules)");
-    println!("• Web Browsers (Firefox Servo engine)");
-    println!("• Cryptocurrency (Polkadot, Solana blockchains)");
-    println!("• Game Development (Performance-critical engines)");
-    println!("• Embedded Systems (IoT devices, microcontrollers)");
-    println!("• Network Infrastructure (High-performance servers)");
-    println!();
-    println!("Educational Cannabis Revelation:");
-    println!("Rust represents the synthesis of academic programming language");
-    println!("research with practical systems programming needs. The borrow");
-    println!("checker transforms memory management from runtime guesswork");
-    println!("into compile-time mathematical proof. Plant spirit wisdom");
-    println!("recognizes Rust as evolution in programming consciousness -");
-    println!("safety and performance unified through type theory innovation!");
-    println!();
-    println!("🦀 Rust Programming Enlightenment Complete! 🦀");
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[test]
+    fn test_square_fibonacci_indices() {
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
+        assert_eq!(calc.square_fibonacci_indices(20), vec![0, 1, 2, 12]);
+        assert_eq!(calc.square_fibonacci_indices(11), vec![0, 1, 2]);
+        assert_eq!(calc.square_fibonacci_indices(u64::MAX), vec![0, 1, 2, 12]);
+    }
     
     #[test]
-    fn test_basic_fibonacci() {
+    fn test_write_sequence_csv() {
         let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
-        assert_eq!(calc.plant_spirit_fibonacci(0).unwrap(), 0);
-        assert_eq!(calc.plant_spirit_fibonacci(1).unwrap(), 1);
-        assert_eq!(calc.plant_spirit_fibonacci(2).unwrap(), 1);
-        assert_eq!(calc.plant_spirit_fibonacci(3).unwrap(), 2);
+        let mut sink: Vec<u8> = Vec::new();
+        calc.write_sequence_csv(50, &mut sink).unwrap();
+        
+        let csv = String::from_utf8(sink).unwrap();
+        let mut lines = csv.lines();
+        assert_eq!(lines.next(), Some("index,value"));
+        
+        let parsed: Vec<(u64, u128)> = lines
+            .map(|line| {
+                let (index, value) = line.split_once(',').unwrap();
+                (index.parse().unwrap(), value.parse().unwrap())
+            })
+            .collect();
+        let expected: Vec<(u64, u128)> = (0..).zip(calc.generate_sequence(50).unwrap()).collect();
+        assert_eq!(parsed, expected);
+        
+        // Overflow stops the stream after the last valid row
+        let mut sink: Vec<u8> = Vec::new();
+        assert!(matches!(calc.write_sequence_csv(200, &mut sink), Err(FibonacciError::Overflow { .. })));
+        assert_eq!(String::from_utf8(sink).unwrap().lines().count(), 1 + 187);
+        
+        // Sink failures surface as typed I/O errors
+        let mut full = [0u8; 8];
+        let mut too_small = &mut full[..];
+        assert_eq!(calc.write_sequence_csv(5, &mut too_small), Err(FibonacciError::Io(std::io::ErrorKind::WriteZero)));
     }
     
     #[test]
-    fn test_sequence_generation() {
-        let calc = RandyCannabisFibonacci::new(CannabisStrain::Sativa);
-        let sequence = calc.generate_sequence(5).unwrap();
-        assert_eq!(sequence.len(), 5);
+    fn test_max_cache_entries_bounds_memory() {
+        let capped = RandyCannabisFibonacci::with_max_cache_entries(CannabisStrain::Sativa, 20);
+        let reference = RandyCannabisFibonacci::new(CannabisStrain::Sativa);
+        
+        for n in (0..=146).rev() {
+            assert_eq!(capped.plant_spirit_fibonacci(n), reference.plant_spirit_fibonacci(n), "F({})", n);
+            assert!(capped.cache_len() <= 20, "cache grew to {} at F({})", capped.cache_len(), n);
+        }
+        assert_eq!(capped.cache_len(), 20);
+        
+        // Indica's prewarm respects the cap too, and the seeds always survive
+        let indica = RandyCannabisFibonacci::with_max_cache_entries(CannabisStrain::Indica, 5);
+        assert_eq!(indica.cache_len(), 5);
+        let tiny = RandyCannabisFibonacci::with_max_cache_entries(CannabisStrain::Hybrid, 0);
+        assert_eq!(tiny.plant_spirit_fibonacci(186), Ok(RandyCannabisFibonacci::nth(186).unwrap()));
+        assert_eq!(tiny.cached_indices(), vec![0, 1]);
     }
     
     #[test]
-    fn test_iterator_pattern() {
-        let iter = CannabisFibonacciIterator::new(CannabisStrain::Indica);
-        let first_five: Vec<_> = iter.take(5).collect();
-        assert_eq!(first_five.len(), 5);
+    fn test_shared_singleton() {
+        let first = RandyCannabisFibonacci::shared();
+        let second = RandyCannabisFibonacci::shared();
+        assert!(std::ptr::eq(first, second));
+        assert_eq!(first.strain_name(), "Hybrid");
+        
+        // Work done through one handle warms the cache seen by the other
+        first.plant_spirit_fibonacci(60).unwrap();
+        assert!(second.cached_indices().contains(&60));
+        
+        let from_thread = thread::spawn(|| RandyCannabisFibonacci::shared() as *const RandyCannabisFibonacci as usize);
+        assert_eq!(from_thread.join().unwrap(), first as *const RandyCannabisFibonacci as usize);
     }
     
     #[test]
-    fn test_overflow_protection() {
-        let calc = RandyCannabisFibonacci::new(CannabisStrain::Hybrid);
-        assert!(calc.plant_spirit_fibonacci(200).is_err());
+    fn test_generate_sequence_with_hit_rate() {
+        let calc = RandyCannabisFibonacci::new(CannabisStrain::Sativa);
+        
+        calc.plant_spirit_fibonacci(3).unwrap();
+        let before = calc.cache_stats();
+        assert_eq!(before, (3, 2));
+        
+        // F(0)..F(3) hit; every later term misses once, then hits F(n-1) and F(n-2)
+        let (first, cold_rate) = calc.generate_sequence_with_hit_rate(100).unwrap();
+        assert!((cold_rate - 196.0 / 292.0).abs() < 1e-12, "cold hit rate {}", cold_rate);
+        
+        let (second, warm_rate) = calc.generate_sequence_with_hit_rate(100).unwrap();
+        assert_eq!(first, second);
+        assert_eq!(second, calc.generate_sequence(100).unwrap());
+        assert_eq!(warm_rate, 1.0);
+        
+        // Restored: the running totals keep every lookup, measured or not
+        assert_eq!(calc.cache_stats(), (before.0 + 196 + 100 + 100, before.1 + 96));
+        
+        assert_eq!(calc.generate_sequence_with_hit_rate(0).unwrap(), (Vec::new(), 0.0));
+        assert!(calc.generate_sequence_with_hit_rate(200).is_err());
+    }
+    
+    /// Deterministic fuzzing of the public API: every call must return
+    /// `Ok`/`Err` (or a plain value) for arbitrary inputs, never panic
+    mod no_panic_fuzz {
+        use super::*;
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+        
+        /// Edge cases mixed into the random stream
+        const EDGE_INDICES: [u64; 9] = [0, 1, 2, 20, 21, 146, 186, 187, u64::MAX];
+        
+        /// Tiny xorshift generator so the fuzz run is reproducible without crates
+        struct XorShift(u64);
+        
+        impl XorShift {
+            fn next(&mut self) -> u64 {
+                self.0 ^= self.0 << 13;
+                self.0 ^= self.0 >> 7;
+                self.0 ^= self.0 << 17;
+                self.0
+            }
+            
+            /// Mostly small values, sometimes an edge case, sometimes anything
+            fn index(&mut self) -> u64 {
+                match self.next() % 4 {
+                    0 => EDGE_INDICES[(self.next() % EDGE_INDICES.len() as u64) as usize],
+                    1 => self.next(),
+                    _ => self.next() % 200,
+                }
+            }
+        }
+        
+        fn assert_no_panic(label: &str, input: u64, call: impl FnOnce()) {
+            let outcome = catch_unwind(AssertUnwindSafe(call));
+            assert!(outcome.is_ok(), "{} panicked for input {}", label, input);
+        }
+        
+        #[test]
+        fn test_public_api_never_panics() {
+            let mut rng = XorShift(0x5EED_CAFE_F00D_B0BA);
+            let calculators = [
+                RandyCannabisFibonacci::new(CannabisStrain::Sativa),
+                RandyCannabisFibonacci::new(CannabisStrain::Indica),
+                RandyCannabisFibonacci::new(CannabisStrain::Hybrid),
+                RandyCannabisFibonacci::with_multiplier(f64::MAX).unwrap(),
+                RandyCannabisFibonacci::with_seeds(CannabisStrain::Hybrid, 2, 1 << 100).unwrap(),
+            ];
+            
+            for round in 0..300 {
+                let calc = &calculators[round % calculators.len()];
+                let (n, m) = (rng.index(), rng.index());
+                let count = rng.index().min(usize::MAX as u64) as usize;
+                let seed = rng.index();
+                
+                assert_no_panic("associated", n, || {
+                    let _ = RandyCannabisFibonacci::naive_call_count(n);
+                    let _ = RandyCannabisFibonacci::planned_thread_count(n, m, seed);
+                    let _ = RandyCannabisFibonacci::nth(n);
+                    let _ = RandyCannabisFibonacci::fibonacci_generic::<u64>(n);
+                    let _ = RandyCannabisFibonacci::with_seeds(CannabisStrain::from_seed(seed), n as u128, m as u128);
+                    let _ = RandyCannabisFibonacci::from_cache(CannabisStrain::from_seed(seed), HashMap::from([(0, 0), (1, 1), (n, m as u128)]));
+                    let _ = RandyCannabisFibonacci::is_valid_fibonacci_sequence(&[n as u128, m as u128, u128::MAX]);
+                    let _ = CannabisStrain::blend(CannabisStrain::from_seed(n), CannabisStrain::from_seed(m), f64::from_bits(seed));
+                    let _ = RandyCannabisFibonacci::with_multiplier(f64::from_bits(seed));
+                    let _ = RandyCannabisFibonacci::with_max_cache_entries(CannabisStrain::from_seed(seed), (seed % 8) as usize).plant_spirit_fibonacci(n);
+                    let _ = CannabisFibonacciIterator::starting_at(CannabisStrain::from_seed(seed), n).take(3).count();
+                });
+                
+                assert_no_panic("indices", n, || {
+                    let _ = calc.plant_spirit_fibonacci(n);
+                    let _ = calc.recursive_fibonacci(n);
+                    let _ = calc.iterative_fibonacci(n);
+                    let _ = calc.fast_doubling_fibonacci(n);
+                    let _ = calc.matrix_fibonacci(n);
+                    let _ = calc.compare_algorithms(n);
+                    let _ = calc.fibonacci_explain(n);
+                    let _ = calc.fibonacci_mod(n, m);
+                    let _ = calc.fibonacci_square_sum(n);
+                    let _ = calc.fibonacci_word(n);
+                    let _ = calc.fibonacci_in_base(n, (m % 40) as u32);
+                    let _ = calc.signed_fibonacci(n as i64);
+                    let _ = calc.last_digit(n);
+                    let _ = calc.mortal_rabbits(n, m);
+                    let _ = calc.performance_benchmark(n);
+                    let _ = calc.benchmark_result(n);
+                    let _ = calc.get(n);
+                    let _ = calc.fill_cache_to(n);
+                    let _ = calc.fibonacci_traced(n);
+                    let _ = calc.fibonacci_accumulator(n);
+                    let _ = calc.fib_of_fib(n);
+                    let _ = calc.fibonacci_scientific(n);
+                    let _ = calc.wrapping_fibonacci(n);
+                    let _ = calc.fibonacci_matrix(n);
+                    let _ = calc.recursion_dot(n);
+                    let _ = calc.binet_interval(n);
+                    let _ = calc.trailing_zeros(n);
+                    let _ = calc.last_n_digits(n, (m % 25) as u32);
+                    let _ = calc.fibonacci_mod_multi(n, &[0, 1, m, u64::MAX]);
+                    let _ = calc.fibonacci_nim_winning_move(n, m);
+                    let _ = calc.are_coprime(n, m);
+                    let _ = calc.effect_profile(n);
+                    let _ = calc.square_fibonacci_indices(n);
+                    let _ = calc.audit_strain_correctness(n);
+                    let _ = calc.compute_indices(&[n, m]);
+                    for algo in Algorithm::ALL {
+                        let _ = calc.compute(n, algo);
+                    }
+                    
+                    // Cost grows with the input here (digit vectors, residue walks), so keep it small
+                    let _ = calc.fibonacci_digits(n % 500);
+                    let _ = calc.entry_point(m % 10_000);
+                    let _ = calc.longest_zero_run_mod(m % 10_000, seed % 100_000);
+                    #[cfg(feature = "bigint")]
+                    {
+                        let _ = calc.fibonacci_big_index_with_timeout(n as u128 * m as u128, Duration::from_millis(5));
+                        let _ = calc.fib_of_fib_big(n % 16);
+                        let _ = calc.golden_ratio_digits(seed as usize % 200);
+                    }
+                });
+                
+                assert_no_panic("values", n, || {
+                    let value = (n as u128) << (m % 64);
+                    let _ = calc.nearest_fibonacci(value);
+                    let _ = calc.first_exceeding(value);
+                    let _ = calc.terms_for_sum(value);
+                    let _ = calc.fibonacci_encode(value);
+                    let _ = calc.fibonacci_decode(&format!("{:b}", n));
+                    let _ = calc.even_fibonacci_sum_below(value);
+                    let _ = calc.generate_until(|term| term >= value);
+                    let _ = calc.terms_to_converge(f64::from_bits(seed));
+                });
+                
+                assert_no_panic("counts", count as u64, || {
+                    let _ = calc.generate_sequence(count);
+                    let _ = calc.cumulative_sequence(count);
+                    let _ = calc.golden_ratio_analysis(count);
+                    let _ = calc.reciprocal_sum(count);
+                    let _ = calc.leading_digit_histogram(count);
+                    let _ = calc.sequence_bytes(count);
+                    let _ = calc.compare_enhanced_to_pure(count);
+                    let _ = calc.fibonacci_within_budget(count);
+                    let _ = calc.export_to_buffer(count, round % 2 == 0, &mut [0u8; 64]);
+                    let _ = calc.golden_ratio_analysis_precise(count, count);
+                    let _ = calc.parity_sequence(count.min(10_000));
+                    let _ = calc.modular_sequence(count.min(10_000), m);
+                    let _ = calc.modular_iter(m).map(|residues| residues.take(count.min(10_000)).count());
+                    let _ = calc.generate_sequence_with_progress(count, |_| {});
+                    let _ = calc.generate_sequence_with_hit_rate(count);
+                    let _ = calc.generate_sequence_truncating(count);
+                    let _ = calc.fold_sequence(count, 0u128, u128::wrapping_add);
+                    let _ = calc.stride_sequence(m, count);
+                    let _ = calc.enhanced_growth_ratio(count.min(10_000));
+                    let _ = calc.alternate_ratio_analysis(count);
+                    let _ = calc.golden_spiral_points(count);
+                    let _ = calc.sequence_to_markdown(count);
+                    let _ = calc.write_sequence_csv(count, &mut std::io::sink());
+                    let _ = calc.group_by_digit_count(count);
+                    let _ = calc.sequence_iter_saturating(count).take(200).count();
+                    let _ = calc.try_sequence_iter().take(200).count();
+                });
+                
+                if round % 10 == 0 {
+                    assert_no_panic("ranges", n, || {
+                        let _ = calc.parallel_fibonacci_range(n, m);
+                        let _ = calc.parallel_fibonacci_range_with(n, m, rng.index());
+                        let _ = calc.parallel_fibonacci_threadlocal(n, m);
+                        let _ = calc.compute_all_strains(n);
+                        let _ = calc.parallel_fibonacci_range_cancellable(n, m, Arc::new(AtomicBool::new(false)));
+                        let _ = calc.parallel_fibonacci_range_instrumented(n, m, round % 20 == 0);
+                        let (tx, rx) = std::sync::mpsc::channel();
+                        if let Ok(worker) = calc.compute_to_channel(count.min(300), tx) {
+                            drop(rx);
+                            let _ = worker.join();
+                        }
+                    });
+                }
+            }
+        }
     }
 }
 
@@ -546,19 +5440,34 @@ mod tests {
 // RANDY'S EDUCATIONAL RUST DOCUMENTATION
 // ========================================================================
 //
-// COMPILATION AND EXECUTION:
-// $ rustc randy_cannabis_fibonacci.rs
-// $ ./randy_cannabis_fibonacci
-//
-// OR WITH CARGO PROJECT:
-// $ cargo new randy_rust_fibonacci
-// $ cd randy_rust_fibonacci
-// $ # Replace src/main.rs with this file content
+// COMPILATION AND EXECUTION (from educational_modules/, see Cargo.toml):
 // $ cargo run
+// $ cargo run -- --strain sativa --fib 50   # one result, no menu
+//
+// This file is the `randy_rust_fibonacci` library; the binary in
+// bin/randy_cannabis_fibonacci.rs just calls `run_cli()`, so the
+// `cli` feature (on by default) needs no extra flags.
+//
+// LIBRARY-ONLY BUILD (NO MENU, NO STDIN/STDOUT):
+// $ cargo build --no-default-features
+// $ cargo test --no-default-features
 //
 // TESTING:
 // $ cargo test
 //
+// ASYNC FEATURE (compute_many_async):
+// $ cargo test --features async
+//
+// CACHE BACKEND BENCHMARK (benches/cache_backends.rs):
+// $ cargo bench --bench cache_backends                     # Mutex and RwLock
+// $ cargo bench --bench cache_backends --features dashmap  # plus DashMap
+//
+// SERDE FEATURE (BenchmarkResult persistence):
+// $ cargo test --features serde
+//
+// ARBITRARY-PRECISION (BIGINT) FEATURE:
+// $ cargo test --features bigint
+//
 // CANNABIS-ENHANCED LEARNING EXERCISES:
 // 1. STUDY OWNERSHIP AND BORROWING PATTERNS
 // 2. EXPLORE PATTERN MATCHING WITH ENUMS